@@ -8,13 +8,14 @@ use ::stackvec::prelude::*;
 extern crate test;
 use ::test::{
 	Bencher,
+	black_box,
 };
 
 #[bench]
 fn stackvec_extend_by_ref (benchmark: &mut Bencher)
 {
     benchmark.iter(|| {
-        for n in 0 .. (0x400 - 8) {
+        for n in 0 .. black_box(0x400 - 8) {
         	let mut vec = StackVec::<[_; 0x400]>::new();
         	vec.extend(
         		Iterator::chain(
@@ -23,6 +24,7 @@ fn stackvec_extend_by_ref (benchmark: &mut Bencher)
 				)
 				.by_ref()
 	        );
+        	black_box(&vec);
 	    }
     });
 }
@@ -31,7 +33,7 @@ fn stackvec_extend_by_ref (benchmark: &mut Bencher)
 fn vec_extend_by_ref (benchmark: &mut Bencher)
 {
     benchmark.iter(|| {
-        for n in 0 .. (0x400 - 8) {
+        for n in 0 .. black_box(0x400 - 8) {
         	let mut vec = Vec::with_capacity(0x400);
 	        vec.extend(
 	        	Iterator::chain(
@@ -40,6 +42,7 @@ fn vec_extend_by_ref (benchmark: &mut Bencher)
 				)
 				.by_ref()
 	        );
+	        black_box(&vec);
     	}
     });
 }
@@ -48,7 +51,7 @@ fn vec_extend_by_ref (benchmark: &mut Bencher)
 fn stackvec_extend (benchmark: &mut Bencher)
 {
     benchmark.iter(|| {
-        for n in 0 .. (0x400 - 8) {
+        for n in 0 .. black_box(0x400 - 8) {
         	let mut vec = StackVec::<[_; 0x400]>::new();
         	vec.extend(
         		Iterator::chain(
@@ -56,6 +59,7 @@ fn stackvec_extend (benchmark: &mut Bencher)
 			    	b"StackVec".iter().map(|&b| b as u16),
 				)
 	        );
+        	black_box(&vec);
 	    }
     });
 }
@@ -64,7 +68,7 @@ fn stackvec_extend (benchmark: &mut Bencher)
 fn vec_extend (benchmark: &mut Bencher)
 {
     benchmark.iter(|| {
-        for n in 0 .. (0x400 - 8) {
+        for n in 0 .. black_box(0x400 - 8) {
         	let mut vec = Vec::with_capacity(0x400);
 	        vec.extend(
 	        	Iterator::chain(
@@ -72,6 +76,7 @@ fn vec_extend (benchmark: &mut Bencher)
 			    	b"StackVec".iter().map(|&b| b as u16),
 				)
 	        );
+	        black_box(&vec);
     	}
     });
 }
@@ -80,13 +85,14 @@ fn vec_extend (benchmark: &mut Bencher)
 fn stackvec_from_iter (benchmark: &mut Bencher)
 {
     benchmark.iter(|| {
-        for n in 0 .. (0x400 - 8) {
-        	let _vec = StackVec::<[_; 0x400]>::from_iter(
+        for n in 0 .. black_box(0x400 - 8) {
+        	let vec = StackVec::<[_; 0x400]>::from_iter(
         		Iterator::chain(
 			    	(0 .. n).map(|x| x * x),
 			    	b"StackVec".iter().map(|&b| b as u16),
 				)
 	        );
+        	black_box(&vec);
 	    }
     });
 }
@@ -95,13 +101,14 @@ fn stackvec_from_iter (benchmark: &mut Bencher)
 fn vec_from_iter (benchmark: &mut Bencher)
 {
     benchmark.iter(|| {
-        for n in 0 .. (0x400 - 8) {
-	        let _vec = Vec::from_iter(
+        for n in 0 .. black_box(0x400 - 8) {
+	        let vec = Vec::from_iter(
 	        	Iterator::chain(
 			    	(0 .. n).map(|x| x * x),
 			    	b"StackVec".iter().map(|&b| b as u16),
 				)
 	        );
+	        black_box(&vec);
     	}
     });
 }
@@ -110,13 +117,14 @@ fn vec_from_iter (benchmark: &mut Bencher)
 fn array_from_iter (benchmark: &mut Bencher)
 {
     benchmark.iter(|| {
-        for n in 0 .. (0x400 - 8) {
-        	let _array = <[_; 0x400]>::try_from_iter(
+        for n in 0 .. black_box(0x400 - 8) {
+        	let array = <[_; 0x400]>::try_from_iter(
         		Iterator::chain(
 			    	(0 .. n).map(|x| x * x),
 			    	b"StackVec".iter().map(|&b| b as u16),
 				)
 	        );
+        	black_box(&array);
 	    }
     });
 }
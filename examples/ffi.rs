@@ -0,0 +1,51 @@
+//! Example of a `StackVec` shared with C code.
+// $ cargo run --example ffi
+//
+// Given its `#[repr(C)]` layout, a `StackVec<[u32; 8]>` is seen from C
+// (e.g., in a header written by hand or generated by `cbindgen`) as:
+//
+// ```c
+// #include <stddef.h>
+// #include <stdint.h>
+//
+// typedef struct {
+//     uint32_t array[8]; /* only the first `len` elements are initialised */
+//     size_t len;
+// } StackVec_uint32_t_8;
+//
+// uint32_t sum (StackVec_uint32_t_8 const * numbers);
+// ```
+
+extern crate stackvec; use ::stackvec::prelude::*;
+
+pub type StackVecU32x8 = StackVec<[u32; 8]>;
+
+/// What C code would see: the same layout, with no Rust invariants.
+#[repr(C)]
+pub struct StackVec_uint32_t_8 {
+    array: [u32; 8],
+    len: usize,
+}
+
+/// `uint32_t sum (StackVec_uint32_t_8 const * numbers);`
+///
+/// # Safety
+///
+/// `numbers` must point to a valid `StackVec_uint32_t_8`.
+#[no_mangle]
+pub unsafe extern "C" fn sum (
+    numbers: *const StackVec_uint32_t_8,
+) -> u32
+{
+    let numbers = &*numbers;
+    numbers.array[.. numbers.len].iter().sum()
+}
+
+fn main ()
+{
+    let numbers: StackVecU32x8 = (1 ..= 4).collect();
+    let total = unsafe {
+        sum(&numbers as *const StackVecU32x8 as *const StackVec_uint32_t_8)
+    };
+    assert_eq!(total, 10);
+}
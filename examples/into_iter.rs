@@ -2,7 +2,7 @@
 
 #![allow(unused_variables)]
 
-extern crate stackvec; use ::stackvec::prelude::*;
+extern crate stackvec; use ::stackvec::IntoIter;
 
 fn main ()
 {
@@ -14,10 +14,10 @@ fn main ()
     ];
 
     // Collect / chain all the vectors together
-    let flattened: Vec<u8> = vecs_array
-                                .into_iter()
+    // (`IntoIter::from` iterates by value on any compiler and edition)
+    let flattened: Vec<u8> = IntoIter::from(vecs_array)
                                 .flatten()
                                 .collect()
     ;
     assert_eq!(flattened, vec![1, 2, 3, 4, 5, 6]);
-}
\ No newline at end of file
+}
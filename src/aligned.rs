@@ -0,0 +1,165 @@
+//! Over-aligned wrappers around [`Array`]s, to be used as the backing storage
+//! of a [`StackVec`] (_e.g._, for SIMD).
+
+use super::*;
+
+use self::array::Sealed;
+
+macro_rules! impl_aligned {
+    ($(
+        $(#[$meta:meta])*
+        $Aligned:ident = $align:tt;
+    )*) => ($(
+        $(#[$meta])*
+        #[repr(C, align($align))]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $Aligned<A: Array>(pub A);
+
+        impl<A: Array> $Aligned<A> {
+            /// The alignment (in bytes) guaranteed by this wrapper.
+            pub const ALIGN: usize = $align;
+
+            /// Unwraps the inner [`Array`].
+            #[inline(always)]
+            pub fn into_inner (
+                self: Self,
+            ) -> A
+            {
+                self.0
+            }
+        }
+
+        impl<A: Array> From<A> for $Aligned<A> {
+            #[inline(always)]
+            fn from (
+                array: A,
+            ) -> Self
+            {
+                $Aligned(array)
+            }
+        }
+
+        impl<A: Array> Sealed for $Aligned<A> {}
+        unsafe impl<A: Array> Array for $Aligned<A> {
+            type Item = A::Item;
+
+            const LEN: usize = A::LEN;
+
+            type Map<U> = $Aligned<A::Map<U>>;
+
+            #[inline(always)]
+            fn as_ptr (
+                self: &Self,
+            ) -> *const Self::Item
+            {
+                self.0.as_ptr()
+            }
+
+            #[inline(always)]
+            fn as_mut_ptr (
+                self: &mut Self,
+            ) -> *mut Self::Item
+            {
+                self.0.as_mut_ptr()
+            }
+        }
+    )*)
+}
+
+impl_aligned! {
+    /// An [`Array`] aligned to (at least) 16 bytes.
+    ///
+    /// See [`Aligned64`] for more info.
+    Aligned16 = 16;
+
+    /// An [`Array`] aligned to (at least) 32 bytes.
+    ///
+    /// See [`Aligned64`] for more info.
+    Aligned32 = 32;
+
+    /// An [`Array`] aligned to (at least) 64 bytes.
+    ///
+    /// Since it is itself an [`Array`] (with the same
+    /// [`Item`][`Array::Item`] and [`LEN`][`Array::LEN`] as the wrapped one),
+    /// it can be used as the backing storage of a [`StackVec`], in which case
+    /// the buffer of the latter, and thus its
+    /// [`.as_ptr()`][`slice::as_ptr`], is guaranteed to be 64-byte aligned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// use ::stackvec::Aligned64;
+    ///
+    /// let mut samples = StackVec::<Aligned64<[f32; 256]>>::new();
+    /// samples.extend((0 .. 256).map(|x| x as f32));
+    /// assert_eq!(samples.as_ptr() as usize % 64, 0);
+    ///
+    /// let Aligned64(array) = samples.try_into().unwrap();
+    /// assert_eq!(array[255], 255.);
+    /// ```
+    Aligned64 = 64;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use super::*;
+
+    fn assert_aligned<T> (ptr: *const T, align: usize)
+    {
+        assert_eq!(ptr as usize % align, 0, "{:p} is not {}-aligned", ptr, align);
+    }
+
+    #[test]
+    fn stackvec_is_aligned ()
+    {
+        // misalign the stack on purpose
+        let (_, mut vec16, _, mut vec32, _, mut vec64) = (
+            0_u8, StackVec::<Aligned16<[u8; 3]>>::new(),
+            0_u8, StackVec::<Aligned32<[u8; 5]>>::new(),
+            0_u8, StackVec::<Aligned64<[f32; 256]>>::new(),
+        );
+        vec16.extend(0 .. 2);
+        vec32.extend(0 .. 4);
+        vec64.extend((0 .. 256).map(|x| x as f32));
+        assert_aligned(vec16.as_ptr(), 16);
+        assert_aligned(vec32.as_ptr(), 32);
+        assert_aligned(vec64.as_ptr(), 64);
+        assert_eq!(vec64.len(), 256);
+        assert_eq!(vec64[255], 255.);
+    }
+
+    #[test]
+    fn boxed_and_copy_stackvecs_are_aligned ()
+    {
+        let vecs: Vec<(u8, StackVec<Aligned64<[u8; 7]>>)> =
+            (0 .. 8)
+            .map(|n| (n, StackVec::from_iter(0 .. n)))
+            .collect();
+        for &(n, ref vec) in &vecs {
+            assert_eq!(vec.len(), n as usize);
+            assert_aligned(vec.as_ptr(), 64);
+        };
+        let copy_vec = CopyStackVec::<Aligned32<[u8; 7]>>::from_iter(0 .. 3);
+        assert_aligned(copy_vec.as_ptr(), 32);
+    }
+
+    #[test]
+    fn flatten_skips_padding ()
+    {
+        let mut chunks = StackVec::<[Aligned16<[u8; 3]>; 4]>::new();
+        chunks.extend(vec![Aligned16([1, 2, 3]), Aligned16([4, 5, 6])]);
+        let flat: StackVec<[u8; 12]> = chunks.flatten();
+        assert_eq!(flat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn array_round_trip ()
+    {
+        let vec = StackVec::from(Aligned32([1_u16, 2, 3]));
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+        let array: Aligned32<[u16; 3]> = vec.try_into().unwrap();
+        assert_eq!(array.into_inner(), [1, 2, 3]);
+    }
+}
@@ -41,10 +41,23 @@ pub trait Sealed {}
 ///   stackvec = { version = ... , default-features = false }
 ///   ```
 ///
+/// # Safety
+///
+/// An implementor must start with the memory layout of
+/// `[Self::Item; Self::LEN]`: [`LEN`][`Array::LEN`] contiguous
+/// `Self::Item`s, the first of which lies at offset `0`
+/// (trailing padding is allowed, as with the [`Aligned64`] wrappers).
+/// [`StackVec`] relies on this to access the slots of a partially
+/// initialised backing array.
+///
+/// Moreover, [`Map<U>`][`Array::Map`] must have the same
+/// [`LEN`][`Array::LEN`] as `Self`.
+///
 /// [`array`]: https://doc.rust-lang.org/std/primitive.array.html
 /// [this trait]: `::stackvec::Array`
 /// [`StackVec`]: `::stackvec::StackVec`
 /// [`StackVec::CAPACITY`]: `::stackvec::StackVec::CAPACITY`
+/// [`Aligned64`]: `crate::Aligned64`
 pub unsafe trait Array: Sealed + Sized {
     /// `[Item; LEN]`
     type Item: Sized;
@@ -52,6 +65,15 @@ pub unsafe trait Array: Sealed + Sized {
     /// `[Item; LEN]`
     const LEN: usize;
 
+    /// `[U; LEN]`: the same kind of array, but with `U` elements.
+    ///
+    /// Used by [`ArrayExt`] to express the return type of element-wise
+    /// transformations such as [`.map()`][`ArrayExt::map`].
+    ///
+    /// [`ArrayExt`]: `crate::ArrayExt`
+    /// [`ArrayExt::map`]: `crate::ArrayExt::map`
+    type Map<U>: Array<Item = U>;
+
     /// Read-only pointer to the first (`0`-th) element of the array.
     ///
     /// Used to get a pointer to the `n`-th element using [`.offset(n)`].
@@ -81,6 +103,8 @@ macro_rules! impl_array {
 
             const LEN: usize = $N;
 
+            type Map<U> = [U; $N];
+
             #[inline(always)]
             fn as_ptr (
                 self: &Self,
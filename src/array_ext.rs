@@ -0,0 +1,472 @@
+//! By-value combinators on [`Array`]s.
+
+use super::*;
+
+/// [`Array`] extension trait providing by-value, element-wise combinators
+/// that return [`Array`]s of the same [`LEN`][`Array::LEN`].
+///
+/// Unlike going through
+/// `array.into_iter().map(f).try_collect::<[_; N]>()`, these cannot fail:
+/// the [`LEN`][`Array::LEN`] of the output is known to match.
+///
+/// Under the hood, the output is built within a [`StackVec`], so that if a
+/// closure panics, both the elements already produced and the ones not yet
+/// consumed are properly dropped.
+///
+/// Note that the standard library provides an inherent `map` method on
+/// [`array`]s, which takes precedence over [`ArrayExt::map`] on actual
+/// [`array`]s (with the same semantics). It also has unstable methods with
+/// some of these names, which trigger the `unstable_name_collisions` lint:
+/// if that is an issue, use the fully qualified syntax
+/// (_e.g._, `ArrayExt::zip(array, other)`) instead.
+///
+/// # Example
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// let names = ["x", "y", "z"];
+/// let values = [1, 2, 3];
+///
+/// let labels = names
+///     .zip(values)
+///     .map(|(name, value)| format!("{}={}", name, value));
+/// assert_eq!(labels, ["x=1", "y=2", "z=3"]);
+///
+/// assert_eq!(labels.rev().enumerate()[0], (0, "z=3".to_string()));
+/// ```
+///
+/// [`array`]: https://doc.rust-lang.org/std/primitive.array.html
+pub trait ArrayExt: Array {
+    /// Creates an array whose `i`-th element is `f(i)`.
+    ///
+    /// The elements are created in order, from index `0` onwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let names = <[String; 64]>::from_fn(|i| format!("#{}", i));
+    /// assert_eq!(names[63], "#63");
+    /// ```
+    #[inline]
+    fn from_fn<F> (
+        f: F,
+    ) -> Self
+    where
+        F: FnMut(usize) -> Self::Item,
+    {
+        (0 .. Self::LEN)
+            .map(f)
+            .collect::<StackVec<Self>>()
+            .try_into()
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Creates an array whose `i`-th element is `f(i)`, stopping at the
+    /// first error (in which case the elements created so far are dropped).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let args = ["8080", "443"];
+    ///
+    /// let ports = <[u16; 2]>::try_from_fn(|i| args[i].parse());
+    /// assert_eq!(ports, Ok([8080, 443]));
+    ///
+    /// assert!(<[u16; 3]>::try_from_fn(|i| {
+    ///     args.get(i).ok_or("missing port")
+    ///         .map(|arg| arg.parse().unwrap())
+    /// }).is_err());
+    /// ```
+    #[inline]
+    fn try_from_fn<E, F> (
+        mut f: F,
+    ) -> Result<Self, E>
+    where
+        F: FnMut(usize) -> Result<Self::Item, E>,
+    {
+        let mut stackvec = StackVec::<Self>::new();
+        for i in 0 .. Self::LEN {
+            stackvec.push_or_ignore(f(i)?);
+        };
+        Ok(
+            stackvec
+                .try_into()
+                .unwrap_or_else(|_| unreachable!())
+        )
+    }
+
+    /// Creates an array filled with [`Default::default()`] values.
+    ///
+    /// Unlike [`Default`] for [`array`]s, it is not limited to 32 elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let buffers = <[Vec<u8>; 64]>::from_default();
+    /// assert!(buffers.iter().all(Vec::is_empty));
+    /// ```
+    ///
+    /// [`array`]: https://doc.rust-lang.org/std/primitive.array.html
+    #[inline]
+    fn from_default () -> Self
+    where
+        Self::Item: Default,
+    {
+        Self::from_fn(|_| Default::default())
+    }
+
+    /// Creates an array filled with [clones][`Clone`] of `value`.
+    ///
+    /// Unlike `[value; N]`, it does not require the element to be [`Copy`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let greetings = <[String; 64]>::from_clone(&"Hello".to_string());
+    /// assert_eq!(greetings[63], "Hello");
+    /// ```
+    #[inline]
+    fn from_clone (
+        value: &Self::Item,
+    ) -> Self
+    where
+        Self::Item: Clone,
+    {
+        Self::from_fn(|_| value.clone())
+    }
+
+    /// Moves the elements of `self` followed by the ones of `other` into a
+    /// new array.
+    ///
+    /// The [`LEN`][`Array::LEN`] of the output must be the sum of the ones
+    /// of the inputs, which is checked at compile time (when monomorphizing).
+    /// For a runtime check, see
+    /// [`.try_concat_array()`][`ArrayExt::try_concat_array`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let header = [0xca_u8, 0xfe];
+    /// let payload = [1, 2, 3];
+    ///
+    /// let packet: [u8; 5] = header.concat_array(payload);
+    /// assert_eq!(packet, [0xca, 0xfe, 1, 2, 3]);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use ::stackvec::prelude::*;
+    /// let packet: [u8; 4] = [0_u8, 1].concat_array([2, 3, 4]);
+    /// ```
+    #[inline]
+    fn concat_array<B, C> (
+        self: Self,
+        other: B,
+    ) -> C
+    where
+        B: Array<Item = Self::Item>,
+        C: Array<Item = Self::Item>,
+    {
+        const {
+            assert!(
+                Self::LEN + B::LEN == C::LEN,
+                "The lengths of the arrays do not add up",
+            );
+        };
+        self.try_concat_array(other)
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Moves the elements of `self` followed by the ones of `other` into a
+    /// new array, provided its [`LEN`][`Array::LEN`] is the sum of the ones
+    /// of the inputs. Otherwise, the inputs are given back within a
+    /// [`LengthMismatchError`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let result = [0_u8, 1].try_concat_array::<_, [u8; 4]>([2, 3, 4]);
+    /// let (first, second) = result.unwrap_err().0;
+    /// assert_eq!(second, [2, 3, 4]);
+    /// ```
+    #[inline]
+    fn try_concat_array<B, C> (
+        self: Self,
+        other: B,
+    ) -> Result<C, LengthMismatchError<(Self, B)>>
+    where
+        B: Array<Item = Self::Item>,
+        C: Array<Item = Self::Item>,
+    {
+        if Self::LEN + B::LEN != C::LEN {
+            return Err(LengthMismatchError((self, other)));
+        };
+        let mut stackvec = StackVec::<C>::new();
+        stackvec.extend(StackVec::from(self));
+        stackvec.extend(StackVec::from(other));
+        Ok(
+            stackvec
+                .try_into()
+                .unwrap_or_else(|_| unreachable!())
+        )
+    }
+
+    /// Moves the elements of `self` into two arrays: the first
+    /// `B::LEN` ones, and the remaining ones.
+    ///
+    /// The [`LEN`][`Array::LEN`]s of the outputs must add up to the one of
+    /// `self`, which is checked at compile time (when monomorphizing).
+    /// For a runtime check, see
+    /// [`.try_split_array()`][`ArrayExt::try_split_array`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let packet = [0xca_u8, 0xfe, 1, 2, 3];
+    ///
+    /// let (header, payload): ([u8; 2], [u8; 3]) = packet.split_array();
+    /// assert_eq!(header, [0xca, 0xfe]);
+    /// assert_eq!(payload, [1, 2, 3]);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use ::stackvec::prelude::*;
+    /// let (_, _): ([u8; 2], [u8; 2]) = [0_u8, 1, 2, 3, 4].split_array();
+    /// ```
+    #[inline]
+    fn split_array<B, C> (
+        self: Self,
+    ) -> (B, C)
+    where
+        B: Array<Item = Self::Item>,
+        C: Array<Item = Self::Item>,
+    {
+        const {
+            assert!(
+                B::LEN + C::LEN == Self::LEN,
+                "The lengths of the arrays do not add up",
+            );
+        };
+        self.try_split_array()
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Moves the elements of `self` into two arrays: the first
+    /// `B::LEN` ones, and the remaining ones, provided the
+    /// [`LEN`][`Array::LEN`]s of the outputs add up to the one of `self`.
+    /// Otherwise, `self` is given back within a [`LengthMismatchError`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let result = [0_u8, 1, 2].try_split_array::<[u8; 2], [u8; 2]>();
+    /// assert_eq!(result.unwrap_err().0, [0, 1, 2]);
+    /// ```
+    #[inline]
+    fn try_split_array<B, C> (
+        self: Self,
+    ) -> Result<(B, C), LengthMismatchError<Self>>
+    where
+        B: Array<Item = Self::Item>,
+        C: Array<Item = Self::Item>,
+    {
+        if B::LEN + C::LEN != Self::LEN {
+            return Err(LengthMismatchError(self));
+        };
+        let mut elements = StackVec::from(self).into_iter();
+        let first: StackVec<B> = elements.by_ref().take(B::LEN).collect();
+        let second: StackVec<C> = elements.collect();
+        Ok((
+            first.try_into().unwrap_or_else(|_| unreachable!()),
+            second.try_into().unwrap_or_else(|_| unreachable!()),
+        ))
+    }
+
+    /// Returns the array obtained by applying `f` to each element.
+    #[inline]
+    fn map<U, F> (
+        self: Self,
+        f: F,
+    ) -> Self::Map<U>
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        StackVec::from(self)
+            .into_iter()
+            .map(f)
+            .collect::<StackVec<Self::Map<U>>>()
+            .try_into()
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Returns the array obtained by applying the fallible `f` to each
+    /// element, stopping at the first error.
+    ///
+    /// On error, all the elements (the ones already mapped as well as the
+    /// remaining ones) are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let parse = |array: [&str; 3]| array.try_map(str::parse::<u8>);
+    ///
+    /// assert_eq!(parse(["1", "2", "3"]), Ok([1, 2, 3]));
+    /// assert!(parse(["1", "two", "3"]).is_err());
+    /// ```
+    #[inline]
+    fn try_map<U, E, F> (
+        self: Self,
+        mut f: F,
+    ) -> Result<Self::Map<U>, E>
+    where
+        F: FnMut(Self::Item) -> Result<U, E>,
+    {
+        let mut stackvec = StackVec::<Self::Map<U>>::new();
+        for value in StackVec::from(self) {
+            stackvec.push_or_ignore(f(value)?);
+        };
+        Ok(
+            stackvec
+                .try_into()
+                .unwrap_or_else(|_| unreachable!())
+        )
+    }
+
+    /// Pairs up the elements of `self` with the ones of `other`.
+    #[inline]
+    fn zip<U> (
+        self: Self,
+        other: Self::Map<U>,
+    ) -> Self::Map<(Self::Item, U)>
+    {
+        let mut other = StackVec::from(other).into_iter();
+        self.map(|value| {
+            (value, other.next().unwrap_or_else(|| unreachable!()))
+        })
+    }
+
+    /// Pairs up each element with its index.
+    #[inline]
+    fn enumerate (
+        self: Self,
+    ) -> Self::Map<(usize, Self::Item)>
+    {
+        let mut i = 0;
+        self.map(|value| {
+            i += 1;
+            (i - 1, value)
+        })
+    }
+
+    /// Reverses the order of the elements.
+    #[inline]
+    fn rev (
+        self: Self,
+    ) -> Self
+    {
+        StackVec::from(self)
+            .into_iter()
+            .rev()
+            .collect::<StackVec<Self>>()
+            .try_into()
+            .unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl<A: Array> ArrayExt for A {}
+
+#[cfg(test)]
+#[allow(unstable_name_collisions)]
+mod tests {
+    use crate::prelude::*;
+    use crate::Aligned16;
+
+    #[test]
+    fn aligned_arrays ()
+    {
+        let array = Aligned16([1_u8, 2, 3]);
+        let doubled: Aligned16<[u16; 3]> = array.map(|x| 2 * x as u16);
+        assert_eq!(doubled.into_inner(), [2, 4, 6]);
+        assert_eq!(array.rev().into_inner(), [3, 2, 1]);
+    }
+
+    #[test]
+    fn panic_drops_everything ()
+    {
+        use ::std::panic;
+        use ::std::rc::Rc;
+
+        let counter = Rc::new(());
+        let array: [Rc<()>; 8] = StackVec::<[_; 8]>::from_iter(
+            (0 .. 8).map(|_| counter.clone())
+        ).try_into().unwrap();
+        assert_eq!(Rc::strong_count(&counter), 9);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let mut count = 0;
+            ArrayExt::map(array, |rc| {
+                count += 1;
+                if count == 4 { panic!("Boom") };
+                rc
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn constructors ()
+    {
+        let squares = <[usize; 100]>::from_fn(|i| i * i);
+        assert_eq!(squares[99], 99 * 99);
+
+        let mut calls = 0;
+        let result = <[u8; 10]>::try_from_fn(|i| {
+            calls += 1;
+            if i < 5 { Ok(i as u8) } else { Err(i) }
+        });
+        assert_eq!(result, Err(5));
+        assert_eq!(calls, 6);
+
+        let aligned = Aligned16::<[Option<String>; 3]>::from_default();
+        assert_eq!(aligned.into_inner(), [None, None, None]);
+
+        let clones = <[String; 0]>::from_clone(&String::new());
+        assert!(clones.is_empty());
+    }
+
+    #[test]
+    fn concat_and_split_move_elements ()
+    {
+        let words = ["a", "b", "c", "d"].map(String::from);
+        let (first, rest): ([String; 1], [String; 3]) = words.split_array();
+        assert_eq!(first, ["a"]);
+        let (middle, last): ([String; 2], [String; 1]) = rest.split_array();
+        let first: [String; 3] = first.concat_array(middle);
+        let words: [String; 4] = first.concat_array(last);
+        assert_eq!(words, ["a", "b", "c", "d"]);
+
+        let (empty, all): ([String; 0], [String; 4]) = words.split_array();
+        assert!(empty.is_empty());
+        assert!(all.try_split_array::<[_; 3], [_; 0]>().is_err());
+    }
+
+    #[test]
+    fn try_map_short_circuits ()
+    {
+        let mut calls = 0;
+        let result = [1, 2, 0, 4].try_map(|x| {
+            calls += 1;
+            if x == 0 { Err("zero") } else { Ok(10 / x) }
+        });
+        assert_eq!(result, Err("zero"));
+        assert_eq!(calls, 3);
+    }
+}
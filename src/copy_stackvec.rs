@@ -0,0 +1,480 @@
+use super::*;
+
+/// Like a [`StackVec`], but for [`Copy`] items, and thus [`Copy`] itself.
+///
+/// Since a [`StackVec`] owns its elements, it needs to drop them, and thus
+/// implements [`Drop`], which rules out it ever being [`Copy`], even when
+/// its items are plain old data (_e.g._, `StackVec<[u8; 32]>`).
+///
+/// A [`CopyStackVec`] can only be backed by an [`array`] of [`Copy`] items,
+/// so it has no drop glue whatsoever, and can thus be [`Copy`] (and
+/// trivially [`Clone`]), making it usable within [`Copy`] `struct`s.
+///
+/// Apart from that, it is a [`StackVec`] (with the default
+/// [`OverflowPolicy`]):
+///
+///   - it [dereferences][`ops::Deref`] to one, so all the methods of
+///     [`StackVec`] taking `&self` or `&mut self` (and, through it, those of
+///     slices) are directly available;
+///
+///   - the methods taking `self` by value are mirrored here
+///     ([`CopyStackVec::into_raw_parts`], [`CopyStackVec::widen`],
+///     [`CopyStackVec::try_narrow`], [`CopyStackVec::into_array_chunks`]),
+///     and any other one is reachable through
+///     [`StackVec::from`][`From::from`], since it can be converted back and
+///     forth to a [`StackVec`] at no cost.
+///
+/// # Example
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// #[derive(Clone, Copy)]
+/// struct Header {
+///     id: u32,
+///     payload: CopyStackVec<[u8; 32]>,
+/// }
+///
+/// let mut header = Header { id: 42, payload: CopyStackVec::new() };
+/// header.payload.extend(b"Hello".iter().cloned());
+///
+/// let copy = header;
+/// header.payload.push(b'!');
+///
+/// assert_eq!(copy.id, header.id);
+/// assert_eq!(copy.payload.as_slice(), b"Hello");
+/// assert_eq!(header.payload.as_slice(), b"Hello!");
+/// ```
+///
+/// It shares [`StackVec`]'s [`#[repr(C)]` layout](
+/// struct.StackVec.html#layout).
+///
+/// [`array`]: https://doc.rust-lang.org/std/primitive.array.html
+#[repr(C)]
+pub struct CopyStackVec<A: Array + Copy> {
+    // Same fields as `StackVec<A>`'s (its `policy` is a trailing ZST), so that
+    // a `CopyStackVec<A>` can be viewed as a `StackVec<A>`.
+    array: mem::MaybeUninit<A>,
+    len: usize,
+}
+
+impl<A: Array + Copy> Clone for CopyStackVec<A> {
+    #[inline(always)]
+    fn clone (
+        self: &Self,
+    ) -> Self
+    {
+        *self
+    }
+}
+
+impl<A: Array + Copy> Copy for CopyStackVec<A> {}
+
+impl<A: Array + Copy> Default for CopyStackVec<A> {
+    /// Default constructor: new empty [`CopyStackVec`]
+    #[inline(always)]
+    fn default () -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<A: Array + Copy> CopyStackVec<A> {
+    /// The (statically) fixed capacity of the [`CopyStackVec`]
+    pub const CAPACITY: usize = A::LEN;
+
+    /// Constructor: new empty [`CopyStackVec`]
+    ///
+    /// Unlike [`CopyStackVec::default`](struct.CopyStackVec.html#impl-Default),
+    /// it is a `const fn`, and can thus be used to initialise `const`s and
+    /// `static`s.
+    #[inline(always)]
+    pub const fn new () -> Self
+    {
+        debug_assert!(Self::CAPACITY <= isize::MAX as usize);
+        CopyStackVec {
+            len: 0,
+            array: mem::MaybeUninit::uninit(),
+        }
+    }
+
+    /// Constructor: a full [`CopyStackVec`] made out of the given `array`.
+    ///
+    /// This is a `const fn` version of
+    /// [`CopyStackVec::from`](struct.CopyStackVec.html#impl-From%3CA%3E).
+    #[inline(always)]
+    pub const fn from_array (
+        array: A,
+    ) -> Self
+    {
+        CopyStackVec {
+            array: mem::MaybeUninit::new(array),
+            len: A::LEN,
+        }
+    }
+
+    /// Constructor: a [`CopyStackVec`] made out of a (partially initialised)
+    /// backing `array` and the number `len` of its initialised elements.
+    ///
+    /// See [`StackVec::from_raw_parts`].
+    ///
+    /// # Safety
+    ///
+    /// - `len` must be less than or equal to [`CopyStackVec::CAPACITY`];
+    ///
+    /// - the first `len` elements of `array` must be initialised.
+    #[inline(always)]
+    pub const unsafe fn from_raw_parts (
+        array: mem::MaybeUninit<A>,
+        len: usize,
+    ) -> Self
+    {
+        debug_assert!(len <= Self::CAPACITY);
+        CopyStackVec { array, len }
+    }
+
+    /// Decomposes the [`CopyStackVec`] into its (partially initialised)
+    /// backing array and the number of its initialised elements.
+    ///
+    /// See [`StackVec::into_raw_parts`].
+    #[inline(always)]
+    pub const fn into_raw_parts (
+        self: Self,
+    ) -> (mem::MaybeUninit<A>, usize)
+    {
+        (self.array, self.len)
+    }
+
+    /// View of the [`CopyStackVec`] as a [`StackVec`] (also available
+    /// through [`Deref`][`ops::Deref`]).
+    #[inline(always)]
+    pub fn as_stackvec (
+        self: &Self,
+    ) -> &StackVec<A>
+    {
+        // Safety: same layout, and `A::Item : Copy` has no drop glue
+        unsafe { &*(self as *const Self as *const StackVec<A>) }
+    }
+
+    /// Mutable view of the [`CopyStackVec`] as a [`StackVec`] (also
+    /// available through [`DerefMut`][`ops::DerefMut`]).
+    #[inline(always)]
+    pub fn as_mut_stackvec (
+        self: &mut Self,
+    ) -> &mut StackVec<A>
+    {
+        // Safety: same layout, and `A::Item : Copy` has no drop glue, so
+        // overwriting the `StackVec` (_e.g._, with `mem::replace`) cannot
+        // leak nor double-drop anything.
+        unsafe { &mut *(self as *mut Self as *mut StackVec<A>) }
+    }
+
+    /// See [`StackVec::widen`].
+    #[inline]
+    pub fn widen<B> (
+        self: Self,
+    ) -> CopyStackVec<B>
+    where
+        B: Array<Item = A::Item> + Copy,
+    {
+        StackVec::from(self).widen().into()
+    }
+
+    /// See [`StackVec::try_narrow`].
+    #[inline]
+    pub fn try_narrow<B> (
+        self: Self,
+    ) -> Result<CopyStackVec<B>, Self>
+    where
+        B: Array<Item = A::Item> + Copy,
+    {
+        StackVec::from(self)
+            .try_narrow()
+            .map(CopyStackVec::from)
+            .map_err(CopyStackVec::from)
+    }
+
+    /// See [`StackVec::into_array_chunks`].
+    #[inline]
+    pub fn into_array_chunks<B> (
+        self: Self,
+    ) -> ArrayChunks<IntoIter<A>, B>
+    where
+        B: Array<Item = A::Item>,
+    {
+        StackVec::from(self).into_array_chunks()
+    }
+}
+
+impl<A: Array + Copy> ops::Deref for CopyStackVec<A> {
+    type Target = StackVec<A>;
+
+    #[inline(always)]
+    fn deref (
+        self: &Self,
+    ) -> &StackVec<A>
+    {
+        self.as_stackvec()
+    }
+}
+
+impl<A: Array + Copy> ops::DerefMut for CopyStackVec<A> {
+    #[inline(always)]
+    fn deref_mut (
+        self: &mut Self,
+    ) -> &mut StackVec<A>
+    {
+        self.as_mut_stackvec()
+    }
+}
+
+impl<A: Array + Copy> fmt::Debug for CopyStackVec<A>
+where
+    A::Item: fmt::Debug,
+{
+    #[inline]
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        fmt::Debug::fmt(self.as_stackvec(), stream)
+    }
+}
+
+impl<A: Array + Copy> Eq for CopyStackVec<A>
+where
+    A::Item : Eq,
+{}
+
+impl<A: Array + Copy> PartialEq for CopyStackVec<A>
+where
+    A::Item : PartialEq,
+{
+    #[inline(always)]
+    fn eq (
+        self: &Self,
+        other: &Self,
+    ) -> bool
+    {
+        self.as_slice().eq(other.as_slice())
+    }
+}
+
+impl<A: Array + Copy> PartialOrd for CopyStackVec<A>
+where
+    A::Item : PartialOrd,
+{
+    #[inline(always)]
+    fn partial_cmp (
+        self: &Self,
+        other: &Self,
+    ) -> Option<cmp::Ordering>
+    {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<A: Array + Copy> Ord for CopyStackVec<A>
+where
+    A::Item : Ord,
+{
+    #[inline(always)]
+    fn cmp (
+        self: &Self,
+        other: &Self,
+    ) -> cmp::Ordering
+    {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<A: Array + Copy> hash::Hash for CopyStackVec<A>
+where
+    A::Item : hash::Hash,
+{
+    fn hash<H: hash::Hasher> (
+        self: &Self,
+        state: &mut H,
+    )
+    {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<A: Array + Copy> iter::Extend<A::Item> for CopyStackVec<A> {
+    #[inline]
+    fn extend<Iterable: IntoIterator<Item = A::Item>> (
+        self: &mut Self,
+        iterable: Iterable,
+    )
+    {
+        self.as_mut_stackvec().extend(iterable)
+    }
+}
+
+impl<A: Array + Copy> iter::FromIterator<A::Item> for CopyStackVec<A> {
+    #[inline(always)]
+    fn from_iter<Iterable: IntoIterator<Item = A::Item>> (
+        iterable: Iterable,
+    ) -> Self
+    {
+        let mut slf = Self::new();
+        slf.extend(iterable);
+        slf
+    }
+}
+
+impl<A: Array + Copy> From<A> for CopyStackVec<A>
+{
+    #[inline(always)]
+    fn from (
+        array: A,
+    ) -> CopyStackVec<A>
+    {
+        CopyStackVec::from_array(array)
+    }
+}
+
+impl<A: Array + Copy> From<CopyStackVec<A>> for StackVec<A>
+{
+    #[inline]
+    fn from (
+        copy_stackvec: CopyStackVec<A>,
+    ) -> StackVec<A>
+    {
+        let (array, len) = copy_stackvec.into_raw_parts();
+        unsafe { StackVec::from_raw_parts(array, len) }
+    }
+}
+
+impl<A: Array + Copy> From<StackVec<A>> for CopyStackVec<A>
+{
+    #[inline]
+    fn from (
+        stackvec: StackVec<A>,
+    ) -> CopyStackVec<A>
+    {
+        let (array, len) = stackvec.into_raw_parts();
+        unsafe { CopyStackVec::from_raw_parts(array, len) }
+    }
+}
+
+impl<A: Array + Copy> IntoIterator for CopyStackVec<A> {
+    type Item = A::Item;
+
+    type IntoIter = IntoIter<A>;
+
+    #[inline(always)]
+    fn into_iter (
+        self: Self,
+    ) -> Self::IntoIter
+    {
+        StackVec::from(self).into_iter()
+    }
+}
+
+/// Try to promote a [`CopyStackVec`] to a full [`array`]
+///
+/// [`array`]: https://doc.rust-lang.org/std/primitive.array.html
+impl<A: Array + Copy> TryInto<A> for CopyStackVec<A>
+{
+    type Error = IncompleteArrayError<A>;
+
+    #[inline(always)]
+    fn try_into (
+        self: CopyStackVec<A>,
+    ) -> Result<A, Self::Error>
+    {
+        StackVec::from(self).try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn copy ()
+    {
+        let mut vec = CopyStackVec::<[u8; 4]>::from_iter(0 .. 3);
+        let copy = vec;
+        assert_eq!(vec.pop(), Some(2));
+        assert_eq!(copy.as_slice(), &[0, 1, 2]);
+        assert_eq!(vec.as_slice(), &[0, 1]);
+    }
+
+    #[test]
+    fn extend_stops_at_capacity ()
+    {
+        let mut vec = CopyStackVec::<[u8; 4]>::new();
+        vec.extend(0 .. 10);
+        assert!(vec.is_full());
+        assert!(vec.try_push(42).is_err());
+        assert_eq!(vec.as_slice(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn try_into_array ()
+    {
+        let mut vec = CopyStackVec::<[u8; 4]>::from_iter(0 .. 2);
+        assert!(vec.try_into().is_err());
+        vec.fill_with(7);
+        let array: [u8; 4] = vec.try_into().unwrap();
+        assert_eq!(array, [0, 1, 7, 7]);
+    }
+
+    #[test]
+    fn spare_capacity ()
+    {
+        let mut vec = CopyStackVec::<[u8; 8]>::from_iter(0 .. 2);
+        {
+            let (init, spare) = vec.split_at_spare_mut();
+            assert_eq!(init, &[0, 1]);
+            assert_eq!(spare.len(), 6);
+            for (slot, &x) in spare.iter_mut().zip(init.iter()) {
+                *slot = ::std::mem::MaybeUninit::new(x + 2);
+            };
+        }
+        unsafe {
+            vec.set_len(4);
+            vec.as_mut_ptr().add(4).write(4);
+            vec.set_len(5);
+        };
+        assert_eq!(vec.as_slice(), &[0, 1, 2, 3, 4]);
+        assert_eq!(vec.as_ptr(), vec.as_slice().as_ptr());
+    }
+
+    #[test]
+    fn stackvec_round_trip ()
+    {
+        let vec = CopyStackVec::<[u8; 4]>::from_iter(0 .. 3);
+        let stackvec = StackVec::from(vec);
+        assert_eq!(stackvec.as_slice(), vec.as_slice());
+        assert_eq!(CopyStackVec::from(stackvec), vec);
+        assert_eq!(vec.into_iter().rev().collect::<Vec<_>>(), [2, 1, 0]);
+    }
+
+    #[test]
+    fn same_api_as_stackvec ()
+    {
+        let mut vec = CopyStackVec::<[u8; 4]>::new();
+        vec.push(1);
+        vec.insert(0, 0);
+        assert!(vec.try_extend(2 .. 4).is_ok());
+        assert!(vec.try_extend(Some(4)).is_err());
+        assert_eq!(vec.capacity(), 4);
+
+        let wide: CopyStackVec<[u8; 8]> = vec.widen();
+        assert_eq!(wide.as_slice(), vec.as_slice());
+        assert!(wide.try_narrow::<[u8; 2]>().is_err());
+        let narrow = wide.try_narrow::<[u8; 4]>().unwrap();
+        assert!(narrow > CopyStackVec::from_array([0, 1, 2, 2]));
+
+        let chunks: Vec<[u8; 2]> = vec.into_array_chunks().collect();
+        assert_eq!(chunks, [[0, 1], [2, 3]]);
+
+        let (array, len) = vec.into_raw_parts();
+        let vec = unsafe { CopyStackVec::<[u8; 4]>::from_raw_parts(array, len - 1) };
+        assert_eq!(format!("{:?}", vec), "[0, 1, 2]");
+    }
+}
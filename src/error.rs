@@ -2,8 +2,6 @@
 
 use super::*;
 
-use ::std::error::Error;
-
 /// Error returned by [`StackVec::try_push`][`::stackvec::StackVec::try_push]
 /// method.
 #[derive(Clone, Copy, Debug)]
@@ -22,45 +20,679 @@ impl<T> fmt::Display for OutOfCapacityError<T> {
     }
 }
 
-impl<T: fmt::Debug> ::std::error::Error for OutOfCapacityError<T> {
-    fn description (
+impl<T: fmt::Debug> ::std::error::Error for OutOfCapacityError<T> {}
+
+
+/// Error returned by
+/// [`ArrayExt::try_concat_array`][`crate::ArrayExt::try_concat_array`] and
+/// [`ArrayExt::try_split_array`][`crate::ArrayExt::try_split_array`]
+/// when the lengths of the [`Array`]s do not add up.
+///
+/// It gives back the input(s).
+#[derive(Clone, Copy, Debug)]
+pub struct LengthMismatchError<T>(pub T);
+
+impl<T> fmt::Display for LengthMismatchError<T> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        fmt::Display::fmt(
+            "The lengths of the arrays do not add up",
+            stream
+        )
+    }
+}
+
+impl<T: fmt::Debug> ::std::error::Error for LengthMismatchError<T> {}
+
+
+/// Error returned when [`try_collect`][`crate::TryCollect`]ing
+/// into a [`StackVec`] yields more elements than its capacity.
+///
+/// It carries the full [`StackVec`] (with its [`OverflowPolicy`]) and the
+/// first rejected element, so that the caller can decide what to do with
+/// them (_e.g._, spill them to the heap, or drop them).
+///
+/// # Example
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// let error = (0 .. 20)
+///                 .try_collect::<StackVec<[_; 15]>>()
+///                 .unwrap_err();
+/// let (stackvec, rejected) = error.into_inner();
+/// assert!(stackvec.is_full());
+/// assert_eq!(rejected, 15);
+/// ```
+pub struct CollectOverflowError<A: Array, P: OverflowPolicy = Ignore> {
+    stackvec: StackVec<A, P>,
+    rejected: A::Item,
+}
+
+impl<A: Array, P: OverflowPolicy> CollectOverflowError<A, P> {
+    #[inline(always)]
+    pub(in crate) fn new (
+        stackvec: StackVec<A, P>,
+        rejected: A::Item,
+    ) -> Self
+    {
+        debug_assert!(stackvec.is_full());
+        CollectOverflowError { stackvec, rejected }
+    }
+
+    /// Read-only view of the (full) [`StackVec`].
+    #[inline]
+    pub fn as_stackvec (
+        self: &Self,
+    ) -> &StackVec<A, P>
+    {
+        &self.stackvec
+    }
+
+    /// Read-only view of the first rejected element.
+    #[inline]
+    pub fn rejected (
+        self: &Self,
+    ) -> &A::Item
+    {
+        &self.rejected
+    }
+
+    /// Gets back the (full) [`StackVec`] and the first rejected element.
+    #[inline]
+    pub fn into_inner (
+        self: Self,
+    ) -> (StackVec<A, P>, A::Item)
+    {
+        (self.stackvec, self.rejected)
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> fmt::Debug for CollectOverflowError<A, P> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        stream
+            .debug_struct("CollectOverflowError")
+            .field("capacity", &A::LEN)
+            .finish()
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> fmt::Display for CollectOverflowError<A, P> {
+    fn fmt (
         self: &Self,
-    ) -> &str
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
     {
-        "Attempted to add an element to a full StackVec"
+        write!(stream,
+            "more than {} elements to collect into a StackVec",
+            A::LEN,
+        )
     }
 }
 
+impl<A: Array, P: OverflowPolicy> ::std::error::Error for CollectOverflowError<A, P> {}
+
 
 /// Error returned by
 /// [`StackVec::try_into`][`::stackvec::traits::TryInto::try_into]
 /// method.
-#[derive(Clone, Copy, Debug)]
-pub struct IncompleteArrayError;
+///
+/// It gives back the incomplete [`StackVec`] (with its [`OverflowPolicy`]),
+/// so that the elements already collected are not lost.
+///
+/// # Example
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// let error = (0 .. 10)
+///                 .map(|x| x.to_string())
+///                 .try_collect::<[String; 15]>()
+///                 .unwrap_err();
+/// assert_eq!(error.missing(), 5);
+/// assert_eq!(error.to_string(), "collected 10 of 15 elements");
+///
+/// let stackvec: StackVec<[String; 15]> = error.into_inner();
+/// assert_eq!(stackvec[9], "9");
+/// ```
+pub struct IncompleteArrayError<A: Array, P: OverflowPolicy = Ignore> {
+    pub(in crate) stackvec: StackVec<A, P>,
+}
 
-impl fmt::Display for IncompleteArrayError {
+impl<A: Array, P: OverflowPolicy> IncompleteArrayError<A, P> {
+    #[inline(always)]
+    pub(in crate) fn new (
+        stackvec: StackVec<A, P>,
+    ) -> Self
+    {
+        debug_assert!(!stackvec.is_full());
+        IncompleteArrayError { stackvec }
+    }
+
+    /// Number of elements collected (less than [`Array::LEN`]).
+    #[inline]
+    pub fn len (
+        self: &Self,
+    ) -> usize
+    {
+        self.stackvec.len()
+    }
+
+    /// Number of elements that were missing to fill the [`Array`].
+    #[inline]
+    pub fn missing (
+        self: &Self,
+    ) -> usize
+    {
+        A::LEN - self.stackvec.len()
+    }
+
+    /// Read-only view of the incomplete [`StackVec`].
+    #[inline]
+    pub fn as_stackvec (
+        self: &Self,
+    ) -> &StackVec<A, P>
+    {
+        &self.stackvec
+    }
+
+    /// Gets back the incomplete [`StackVec`].
+    #[inline]
+    pub fn into_inner (
+        self: Self,
+    ) -> StackVec<A, P>
+    {
+        self.stackvec
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> Clone for IncompleteArrayError<A, P>
+where
+    A::Item: Clone,
+{
+    fn clone (
+        self: &Self,
+    ) -> Self
+    {
+        IncompleteArrayError { stackvec: self.stackvec.clone() }
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> fmt::Debug for IncompleteArrayError<A, P> {
     fn fmt (
         self: &Self,
         stream: &mut fmt::Formatter,
     ) -> fmt::Result
     {
-        fmt::Display::fmt(self.description(), stream)
+        stream
+            .debug_struct("IncompleteArrayError")
+            .field("len", &self.len())
+            .field("capacity", &A::LEN)
+            .finish()
     }
 }
 
-impl ::std::error::Error for IncompleteArrayError {
-    fn description (
+impl<A: Array, P: OverflowPolicy> fmt::Display for IncompleteArrayError<A, P> {
+    fn fmt (
         self: &Self,
-    ) -> &str
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
     {
-        concat!(
-            "Cannot build an incomplete array.",
+        write!(stream,
+            "collected {} of {} elements",
+            self.len(),
+            A::LEN,
         )
     }
 }
 
+impl<A: Array, P: OverflowPolicy> ::std::error::Error for IncompleteArrayError<A, P> {}
+
+/// Error returned by
+/// [`.try_collect_exact()`][`crate::TryCollect::try_collect_exact`]
+/// when the iterator does not yield exactly [`Array::LEN`] elements.
+pub enum ExactCollectError<A: Array> {
+    /// There were too few elements to fill the [`Array`].
+    ///
+    /// The elements collected so far can be recovered from the inner
+    /// [`IncompleteArrayError`].
+    TooFew(IncompleteArrayError<A>),
+
+    /// There were more elements than the [`Array`] can hold.
+    TooMany {
+        /// The (full) [`Array`] with the first [`Array::LEN`] elements.
+        array: A,
+
+        /// The first surplus element.
+        surplus: A::Item,
+    },
+}
+
+impl<A: Array> fmt::Debug for ExactCollectError<A> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match *self {
+            ExactCollectError::TooFew(ref error) => {
+                stream
+                    .debug_tuple("TooFew")
+                    .field(error)
+                    .finish()
+            },
+            ExactCollectError::TooMany { .. } => {
+                stream
+                    .debug_struct("TooMany")
+                    .field("capacity", &A::LEN)
+                    .finish()
+            },
+        }
+    }
+}
+
+impl<A: Array> fmt::Display for ExactCollectError<A> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match *self {
+            ExactCollectError::TooFew(ref error) => {
+                fmt::Display::fmt(error, stream)
+            },
+            ExactCollectError::TooMany { .. } => {
+                write!(stream,
+                    "more than {} elements to collect",
+                    A::LEN,
+                )
+            },
+        }
+    }
+}
+
+impl<A: Array> ::std::error::Error for ExactCollectError<A> {}
+
+/// Error returned by
+/// [`.try_collect_ok()`][`crate::TryCollect::try_collect_ok`].
+pub enum ShortCircuitError<E, A: Array> {
+    /// An item was an error (`Err(error)`, or `None` for [`Option`]s),
+    /// which stopped the collection. The elements collected so far have been
+    /// dropped.
+    Item(E),
+
+    /// There were not enough (successful) items to fill the [`Array`].
+    Incomplete(IncompleteArrayError<A>),
+}
+
+impl<E: fmt::Debug, A: Array> fmt::Debug for ShortCircuitError<E, A> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match *self {
+            ShortCircuitError::Item(ref error) => {
+                stream
+                    .debug_tuple("Item")
+                    .field(error)
+                    .finish()
+            },
+            ShortCircuitError::Incomplete(ref error) => {
+                stream
+                    .debug_tuple("Incomplete")
+                    .field(error)
+                    .finish()
+            },
+        }
+    }
+}
+
+impl<E: fmt::Display, A: Array> fmt::Display for ShortCircuitError<E, A> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match *self {
+            ShortCircuitError::Item(ref error) => {
+                fmt::Display::fmt(error, stream)
+            },
+            ShortCircuitError::Incomplete(ref error) => {
+                fmt::Display::fmt(error, stream)
+            },
+        }
+    }
+}
+
+impl<E: ::std::error::Error, A: Array> ::std::error::Error
+    for ShortCircuitError<E, A>
+{}
+
+/// Error returned by
+/// [`.try_unzip()`][`crate::IteratorExt::try_unzip`].
+pub enum UnzipError<L: FromStackVec, R: FromStackVec> {
+    /// A pair was yielded while one of the two [`StackVec`]s was already
+    /// full.
+    Overflow {
+        /// The elements collected on the left side so far.
+        left: StackVec<L::Array>,
+
+        /// The elements collected on the right side so far.
+        right: StackVec<R::Array>,
+
+        /// The rejected pair.
+        rejected: (
+            <L::Array as Array>::Item,
+            <R::Array as Array>::Item,
+        ),
+    },
+
+    /// The left [`StackVec`] could not be converted (_e.g._, it was not
+    /// full enough to make an [`Array`]). The right side has been dropped.
+    Left(L::Error),
+
+    /// The right [`StackVec`] could not be converted (_e.g._, it was not
+    /// full enough to make an [`Array`]).
+    Right(R::Error),
+}
+
+impl<L: FromStackVec, R: FromStackVec> fmt::Debug for UnzipError<L, R> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match *self {
+            UnzipError::Overflow { .. } => {
+                stream
+                    .debug_struct("Overflow")
+                    .field("left_capacity", &L::Array::LEN)
+                    .field("right_capacity", &R::Array::LEN)
+                    .finish()
+            },
+            UnzipError::Left(ref error) => {
+                stream
+                    .debug_tuple("Left")
+                    .field(error)
+                    .finish()
+            },
+            UnzipError::Right(ref error) => {
+                stream
+                    .debug_tuple("Right")
+                    .field(error)
+                    .finish()
+            },
+        }
+    }
+}
+
+impl<L: FromStackVec, R: FromStackVec> fmt::Display for UnzipError<L, R> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match *self {
+            UnzipError::Overflow { .. } => {
+                write!(stream,
+                    "more than {} / {} pairs to unzip into StackVecs",
+                    L::Array::LEN,
+                    R::Array::LEN,
+                )
+            },
+            UnzipError::Left(ref error) => {
+                write!(stream, "left side: {}", error)
+            },
+            UnzipError::Right(ref error) => {
+                write!(stream, "right side: {}", error)
+            },
+        }
+    }
+}
+
+impl<L: FromStackVec, R: FromStackVec> ::std::error::Error
+    for UnzipError<L, R>
+{}
+
+/// Error returned by
+/// [`.try_partition()`][`crate::IteratorExt::try_partition`]
+/// when one of the two partitions overflows.
+///
+/// It carries both partitions and the rejected element, so that nothing is
+/// lost.
+///
+/// # Example
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// let error = (0 .. 10)
+///                 .try_partition::<[_; 4]>(|&x| x < 2)
+///                 .unwrap_err();
+/// let (matching, rest, rejected) = error.into_inner();
+/// assert_eq!(matching.as_slice(), &[0, 1]);
+/// assert_eq!(rest.as_slice(), &[2, 3, 4, 5]);
+/// assert_eq!(rejected, 6);
+/// ```
+pub struct PartitionOverflowError<A: Array> {
+    matching: StackVec<A>,
+    rest: StackVec<A>,
+    rejected: A::Item,
+}
+
+impl<A: Array> PartitionOverflowError<A> {
+    #[inline(always)]
+    pub(in crate) fn new (
+        matching: StackVec<A>,
+        rest: StackVec<A>,
+        rejected: A::Item,
+    ) -> Self
+    {
+        debug_assert!(matching.is_full() || rest.is_full());
+        PartitionOverflowError { matching, rest, rejected }
+    }
+
+    /// Read-only view of the elements that satisfied the predicate.
+    #[inline]
+    pub fn matching (
+        self: &Self,
+    ) -> &StackVec<A>
+    {
+        &self.matching
+    }
+
+    /// Read-only view of the elements that did not satisfy the predicate.
+    #[inline]
+    pub fn rest (
+        self: &Self,
+    ) -> &StackVec<A>
+    {
+        &self.rest
+    }
+
+    /// Read-only view of the rejected element.
+    #[inline]
+    pub fn rejected (
+        self: &Self,
+    ) -> &A::Item
+    {
+        &self.rejected
+    }
+
+    /// Gets back both partitions and the rejected element.
+    #[inline]
+    pub fn into_inner (
+        self: Self,
+    ) -> (StackVec<A>, StackVec<A>, A::Item)
+    {
+        (self.matching, self.rest, self.rejected)
+    }
+}
+
+impl<A: Array> fmt::Debug for PartitionOverflowError<A> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        stream
+            .debug_struct("PartitionOverflowError")
+            .field("matching", &self.matching.len())
+            .field("rest", &self.rest.len())
+            .field("capacity", &A::LEN)
+            .finish()
+    }
+}
+
+impl<A: Array> fmt::Display for PartitionOverflowError<A> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        write!(stream,
+            "more than {} elements to partition into a StackVec",
+            A::LEN,
+        )
+    }
+}
+
+impl<A: Array> ::std::error::Error for PartitionOverflowError<A> {}
+
+/// Error returned by [`StackVec::try_extend`] when the iterator yields more
+/// elements than there is room for.
+///
+/// It carries the first rejected element and the rest of the iterator, so
+/// that the elements can be fed somewhere else (_e.g._, a fresh
+/// [`StackVec`]) without losing any of them.
+///
+/// When the lower bound of the iterator's
+/// [`size_hint`][`Iterator::size_hint`] already exceeds the spare capacity,
+/// [`StackVec::try_extend`] fails without consuming anything: there is then
+/// no rejected element, and the [`StackVec`] is left untouched.
+///
+/// # Example
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// let mut buffer = StackVec::<[_; 4]>::new();
+/// let error = buffer.try_extend((0 .. 10).filter(|x| x % 2 == 0)).unwrap_err();
+/// assert_eq!(buffer.as_slice(), &[0, 2, 4, 6]);
+/// assert_eq!(error.rejected(), Some(&8));
+///
+/// // Eager failure: nothing is consumed
+/// let error = buffer.try_extend(10 .. 20).unwrap_err();
+/// assert!(error.rejected().is_none());
+/// assert_eq!(error.into_remaining().count(), 10);
+/// ```
+pub struct ExtendOverflow<I: Iterator> {
+    rejected: Option<I::Item>,
+    remaining: I,
+}
+
+impl<I: Iterator> ExtendOverflow<I> {
+    #[inline(always)]
+    pub(in crate) fn new (
+        rejected: Option<I::Item>,
+        remaining: I,
+    ) -> Self
+    {
+        ExtendOverflow { rejected, remaining }
+    }
+
+    /// Read-only view of the first rejected element, if the iterator was
+    /// polled past the capacity.
+    #[inline]
+    pub fn rejected (
+        self: &Self,
+    ) -> Option<&I::Item>
+    {
+        self.rejected.as_ref()
+    }
+
+    /// Gets back the first rejected element (if any) and the (not yet
+    /// polled) rest of the iterator.
+    #[inline]
+    pub fn into_inner (
+        self: Self,
+    ) -> (Option<I::Item>, I)
+    {
+        (self.rejected, self.remaining)
+    }
+
+    /// Gets back all the elements that did not fit, as an iterator starting
+    /// with the rejected one (if any).
+    #[inline]
+    pub fn into_remaining (
+        self: Self,
+    ) -> iter::Chain<option::IntoIter<I::Item>, I>
+    {
+        self.rejected.into_iter().chain(self.remaining)
+    }
+}
+
+impl<I: Iterator> fmt::Debug for ExtendOverflow<I> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        stream
+            .debug_struct("ExtendOverflow")
+            .field("rejected", &self.rejected.is_some())
+            .field("remaining", &self.remaining.size_hint())
+            .finish()
+    }
+}
+
+impl<I: Iterator> fmt::Display for ExtendOverflow<I> {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        fmt::Display::fmt(
+            "more elements to extend a StackVec with than its spare capacity",
+            stream
+        )
+    }
+}
+
+impl<I: Iterator> ::std::error::Error for ExtendOverflow<I> {}
+
+/// Error representing a `None` item, when
+/// [`.try_collect_ok()`][`crate::TryCollect::try_collect_ok`]ing
+/// [`Option`]s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NoneError;
+
+impl fmt::Display for NoneError {
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        fmt::Display::fmt(
+            "Encountered a `None` element",
+            stream
+        )
+    }
+}
+
+impl ::std::error::Error for NoneError {}
+
 /// Error used generic-wise to extend fake fallible operations from unfallible
 /// ones.
+///
+/// It is, for instance, the [`Error`][`::stackvec::TryFromIterator::Error`]
+/// of the [`TryFromIterator`][`::stackvec::TryFromIterator`]
+/// implementations of the standard library collections, and of
+/// [`Unfallible`][`::stackvec::Unfallible`].
 #[derive(Debug)]
 pub enum UnreachableError {}
 
@@ -75,8 +707,4 @@ impl fmt::Display for UnreachableError {
     }
 }
 
-impl ::std::error::Error for UnreachableError {
-    fn description (&self) -> &str {
-        self.unreachable()
-    }
-}
\ No newline at end of file
+impl ::std::error::Error for UnreachableError {}
\ No newline at end of file
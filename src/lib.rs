@@ -1,7 +1,13 @@
 #![doc(test(attr(deny(warnings))))]
-#![doc(test(attr(allow(unused_variables))))]
+#![doc(test(attr(allow(
+    unused_variables,
+    unstable_name_collisions,
+))))]
 #![warn(missing_docs)]
-#![doc(html_root_url = "https://docs.rs/stackvec/0.1.1")]
+#![doc(html_root_url = "https://docs.rs/stackvec/0.3.0")]
+
+// `self: &Self` is the house style
+#![allow(clippy::needless_arbitrary_self_type)]
 
 #![cfg_attr(feature = "nightly",
     feature(trusted_len, exact_size_is_empty)
@@ -12,10 +18,7 @@
 // )]
 
 #![cfg_attr(feature = "nightly",
-    feature(external_doc)
-)]
-#![cfg_attr(feature = "nightly",
-    doc(include = "../README.md")
+    doc = include_str!("../README.md")
 )]
 #![cfg_attr(not(feature = "nightly"),
     doc = "See [crates.io](https://crates.io/crates/stackvec)"
@@ -34,10 +37,12 @@ use ::std::*;
 pub mod prelude {
     pub use super::{
         StackVec,
-        ArrayIntoIter,
+        CopyStackVec,
         TryInto,
         TryFromIterator,
         TryCollect,
+        IteratorExt,
+        ArrayExt,
     };
 
     pub use ::std::iter::FromIterator;
@@ -46,12 +51,25 @@ pub mod prelude {
 pub use self::array::Array;
 mod array;
 
+pub use self::array_ext::ArrayExt;
+mod array_ext;
+
+pub use self::aligned::{Aligned16, Aligned32, Aligned64};
+mod aligned;
+
 pub mod error;
 use self::error::*;
 
+pub mod policy;
+pub use self::policy::OverflowPolicy;
+use self::policy::Ignore;
+
 mod stackvec;
 pub use self::stackvec::*;
 pub use self::into_iter::Iter as IntoIter;
 
+pub use self::copy_stackvec::CopyStackVec;
+mod copy_stackvec;
+
 #[cfg(test)]
 mod tests;
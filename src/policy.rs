@@ -0,0 +1,396 @@
+//! The [overflow policies][`OverflowPolicy`] of a [`StackVec`].
+
+use super::*;
+
+use self::array::Sealed;
+
+/// Type-level choice of what a [`StackVec`] does when an element is added
+/// to it while it is full.
+///
+/// It is the (defaulted) second type parameter of a [`StackVec`]:
+/// `StackVec<A, P = Ignore>`, and governs [`StackVec::push`],
+/// [`StackVec::insert`], and the [`Extend`][`iter::Extend`]
+/// implementation:
+///
+/// | Policy       | [`push`] / [`insert`] return | on overflow |
+/// |--------------|------------------------------|-------------|
+/// | [`Ignore`]   | `()`                         | the new element is dropped |
+/// | [`Panic`]    | `()`                         | panics |
+/// | [`Error`]    | `Result<(), OutOfCapacityError<T>>` | `Err` (panics within [`Extend`][`iter::Extend`]) |
+/// | [`Saturate`] | `()`                         | the oldest (first) element is evicted to make room |
+///
+/// The other methods (_e.g._, [`StackVec::try_push`],
+/// [`StackVec::push_or_ignore`], or the ones returning errors) behave the
+/// same regardless of the policy.
+///
+/// The policy does not change the layout of the [`StackVec`], and can be
+/// changed with [`StackVec::with_policy`]. It is kept by the methods that
+/// move the elements into another [`StackVec`] (_e.g._,
+/// [`StackVec::widen`], or [`IntoIter::into_remaining`][`crate::IntoIter`]),
+/// and by the errors that give the [`StackVec`] back.
+///
+/// Like [`Vec`]'s allocator, only the default [`Ignore`] policy gets the
+/// [`StackVec::new`], [`Default`] and [`FromIterator`][`iter::FromIterator`]
+/// constructors, so that they need no annotation: with another policy, use
+/// [`StackVec::new_with_policy`] (and [`Extend`][`iter::Extend`] it).
+///
+/// This trait is sealed: it cannot be implemented outside of this crate.
+///
+/// [`push`]: `StackVec::push`
+/// [`insert`]: `StackVec::insert`
+pub trait OverflowPolicy: Sealed + Sized {
+    /// The return type of [`StackVec::push`] and [`StackVec::insert`],
+    /// when adding an element of type `T`.
+    type Output<T>;
+
+    #[doc(hidden)]
+    fn added<T> () -> Self::Output<T>;
+
+    #[doc(hidden)]
+    fn push_overflow<A: Array> (
+        stackvec: &mut StackVec<A, Self>,
+        value: A::Item,
+    ) -> Self::Output<A::Item>;
+
+    #[doc(hidden)]
+    #[inline]
+    fn insert_overflow<A: Array> (
+        stackvec: &mut StackVec<A, Self>,
+        _index: usize,
+        value: A::Item,
+    ) -> Self::Output<A::Item>
+    {
+        Self::push_overflow(stackvec, value)
+    }
+
+    /// Called by [`Extend`][`iter::Extend`] once the [`StackVec`] is full,
+    /// with the iterator of the elements that did not fit (not polled yet).
+    #[doc(hidden)]
+    fn extend_overflow<A: Array, I: Iterator<Item = A::Item>> (
+        stackvec: &mut StackVec<A, Self>,
+        remaining: I,
+    );
+}
+
+/// The default [`OverflowPolicy`]: the elements that do not fit are
+/// silently dropped.
+///
+/// Within [`Extend`][`iter::Extend`], the iterator is not polled past the
+/// capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ignore {}
+
+/// [`OverflowPolicy`] that panics when an element does not fit.
+///
+/// Within [`Extend`][`iter::Extend`], the iterator is polled once past the
+/// capacity, to detect whether there was an element that did not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Panic {}
+
+/// [`OverflowPolicy`] that makes [`StackVec::push`] and
+/// [`StackVec::insert`] return an [`OutOfCapacityError`] (with the element
+/// that did not fit).
+///
+/// Since [`Extend`][`iter::Extend`] cannot return an error, it panics
+/// (like [`Panic`]) rather than silently losing the elements: use
+/// [`StackVec::try_extend`] or
+/// [`.try_collect()`][`crate::TryCollect::try_collect`] to get
+/// an error instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {}
+
+/// [`OverflowPolicy`] that keeps the most recent elements, like a ring
+/// buffer: when an element does not fit, the oldest (first) element of the
+/// [`StackVec`] is evicted (dropped) to make room for it.
+///
+/// Hence, pushing onto a full [`StackVec`] shifts its elements to the left
+/// and appends the new one, and [`Extend`][`iter::Extend`] consumes the
+/// whole iterator, the [`StackVec`] ending up with its last
+/// [`CAPACITY`][`StackVec::CAPACITY`] elements (in order).
+/// Inserting at index `0` into a full [`StackVec`] is a no-op, since the
+/// new element would be the oldest one.
+///
+/// With a capacity of `0`, the elements are dropped.
+///
+/// # Example
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// use ::stackvec::policy::Saturate;
+///
+/// let mut vec = StackVec::<[_; 3], Saturate>::new_with_policy();
+/// vec.extend(0 .. 10);
+/// assert_eq!(vec.as_slice(), &[7, 8, 9]);
+/// vec.push(42);
+/// assert_eq!(vec.as_slice(), &[8, 9, 42]);
+/// vec.insert(1, 27);
+/// assert_eq!(vec.as_slice(), &[27, 9, 42]);
+/// vec.insert(0, 0);
+/// assert_eq!(vec.as_slice(), &[27, 9, 42]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Saturate {}
+
+impl Sealed for Ignore {}
+impl OverflowPolicy for Ignore {
+    type Output<T> = ();
+
+    #[inline(always)]
+    fn added<T> () {}
+
+    #[inline(always)]
+    fn push_overflow<A: Array> (
+        _: &mut StackVec<A, Self>,
+        _: A::Item,
+    )
+    {}
+
+    #[inline(always)]
+    fn extend_overflow<A: Array, I: Iterator<Item = A::Item>> (
+        _: &mut StackVec<A, Self>,
+        _: I,
+    )
+    {}
+}
+
+impl Sealed for Panic {}
+impl OverflowPolicy for Panic {
+    type Output<T> = ();
+
+    #[inline(always)]
+    fn added<T> () {}
+
+    #[inline]
+    fn push_overflow<A: Array> (
+        _: &mut StackVec<A, Self>,
+        _: A::Item,
+    )
+    {
+        overflow_panic(A::LEN)
+    }
+
+    #[inline]
+    fn extend_overflow<A: Array, I: Iterator<Item = A::Item>> (
+        _: &mut StackVec<A, Self>,
+        mut remaining: I,
+    )
+    {
+        if remaining.next().is_some() {
+            overflow_panic(A::LEN)
+        };
+    }
+}
+
+impl Sealed for Error {}
+impl OverflowPolicy for Error {
+    type Output<T> = Result<(), OutOfCapacityError<T>>;
+
+    #[inline(always)]
+    fn added<T> () -> Result<(), OutOfCapacityError<T>>
+    {
+        Ok(())
+    }
+
+    #[inline]
+    fn push_overflow<A: Array> (
+        _: &mut StackVec<A, Self>,
+        value: A::Item,
+    ) -> Result<(), OutOfCapacityError<A::Item>>
+    {
+        Err(OutOfCapacityError(value))
+    }
+
+    #[inline]
+    fn extend_overflow<A: Array, I: Iterator<Item = A::Item>> (
+        _: &mut StackVec<A, Self>,
+        mut remaining: I,
+    )
+    {
+        if remaining.next().is_some() {
+            overflow_panic(A::LEN)
+        };
+    }
+}
+
+impl Sealed for Saturate {}
+impl OverflowPolicy for Saturate {
+    type Output<T> = ();
+
+    #[inline(always)]
+    fn added<T> () {}
+
+    #[inline]
+    fn push_overflow<A: Array> (
+        stackvec: &mut StackVec<A, Self>,
+        value: A::Item,
+    )
+    {
+        let slots = stackvec.as_mut_slice();
+        if let Some(oldest) = slots.first_mut() {
+            *oldest = value;
+            slots.rotate_left(1);
+        };
+    }
+
+    #[inline]
+    fn insert_overflow<A: Array> (
+        stackvec: &mut StackVec<A, Self>,
+        index: usize,
+        value: A::Item,
+    )
+    {
+        // The new element would be the oldest one when `index == 0`.
+        if index > 0 {
+            let slots = stackvec.as_mut_slice();
+            slots[0] = value;
+            slots[.. index].rotate_left(1);
+        };
+    }
+
+    #[inline]
+    fn extend_overflow<A: Array, I: Iterator<Item = A::Item>> (
+        stackvec: &mut StackVec<A, Self>,
+        remaining: I,
+    )
+    {
+        // Overwrites the slots in a circular fashion, and then rotates them
+        // back in order, rather than shifting all the elements each time.
+        let slots = stackvec.as_mut_slice();
+        if slots.is_empty() {
+            return remaining.for_each(drop);
+        };
+        let mut oldest = 0;
+        remaining.for_each(|value| {
+            slots[oldest] = value;
+            oldest += 1;
+            if oldest == slots.len() {
+                oldest = 0;
+            };
+        });
+        slots.rotate_left(oldest);
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn overflow_panic (
+    capacity: usize,
+) -> !
+{
+    panic!("Attempted to add an element to a full StackVec (capacity: {})", capacity)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "full StackVec")]
+    fn panic_on_extend ()
+    {
+        StackVec::<[_; 3], Panic>::new_with_policy().extend(0 .. 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "full StackVec")]
+    fn error_panics_on_extend ()
+    {
+        let mut vec = StackVec::<[_; 3], Error>::new_with_policy();
+        vec.extend((0 .. 4).filter(|_| true));
+    }
+
+    #[test]
+    fn fill_stops_at_capacity ()
+    {
+        let mut vec = StackVec::<[_; 3], Panic>::new_with_policy();
+        vec.extend(Some(0));
+        vec.fill_with(1);
+        assert_eq!(vec.as_slice(), &[0, 1, 1]);
+        vec.fill_using(|| unreachable!());
+    }
+
+    #[test]
+    fn panic_and_error_accept_exact_fits ()
+    {
+        let mut vec = StackVec::<[_; 3], Panic>::new_with_policy();
+        vec.extend(0 .. 3);
+        assert!(vec.is_full());
+        let mut vec = vec.with_policy::<Error>();
+        vec.extend(None);
+        assert_eq!(vec.insert(0, 42).unwrap_err().0, 42);
+        vec.pop();
+        assert!(vec.insert(0, 42).is_ok());
+        assert_eq!(vec.as_slice(), &[42, 0, 1]);
+    }
+
+    #[test]
+    fn ignore_does_not_poll_past_capacity ()
+    {
+        let mut iterator = 0 .. 10;
+        let vec = StackVec::<[_; 3]>::from_iter(iterator.by_ref());
+        assert!(vec.is_full());
+        assert_eq!(iterator.next(), Some(3));
+    }
+
+    // With the `Error` policy, `push` returns a `Result`: these only compile
+    // if the policy has been kept.
+    #[test]
+    fn the_policy_is_kept ()
+    {
+        let vec = StackVec::<[u8; 2], Error>::from_array_with_policy([1, 2]);
+        let mut wide = vec.widen::<[u8; 3]>();
+        assert!(wide.push(3).is_ok());
+        assert_eq!(wide.push(4).unwrap_err().0, 4);
+
+        let mut narrow = wide.try_narrow::<[u8; 3]>().unwrap();
+        assert!(narrow.push(4).is_err());
+
+        let mut iterator = narrow.into_iter();
+        iterator.next();
+        let mut rest = iterator.clone().into_remaining();
+        assert!(rest.push(4).is_ok());
+        assert!(rest.push(5).is_err());
+
+        iterator.next();
+        let incomplete = TryInto::<[u8; 3]>::try_into(iterator.into_remaining());
+        let mut rest = incomplete.unwrap_err().into_inner();
+        assert!(rest.push(4).is_ok());
+
+        let overflow = (0 .. 4).try_collect::<StackVec<[u8; 3], Error>>();
+        let (mut full, _) = overflow.unwrap_err().into_inner();
+        assert!(full.push(4).is_err());
+
+        let chunks = StackVec::<[[u8; 2]; 2], Error>::new_with_policy();
+        let mut flat = chunks.flatten::<[u8; 4]>();
+        assert!(flat.push(0).is_ok());
+    }
+
+    #[test]
+    fn saturate_evicts_the_oldest_element ()
+    {
+        let mut vec = StackVec::<[String; 3], Saturate>::new_with_policy();
+        vec.extend("abcde".chars().map(String::from));
+        assert_eq!(vec.as_slice(), &["c", "d", "e"]);
+        vec.extend("fghi".chars().map(String::from));
+        assert_eq!(vec.as_slice(), &["g", "h", "i"]);
+        vec.push("j".into());
+        assert_eq!(vec.as_slice(), &["h", "i", "j"]);
+        vec.insert(3, "k".into());
+        assert_eq!(vec.as_slice(), &["i", "j", "k"]);
+        vec.insert(1, "l".into());
+        assert_eq!(vec.as_slice(), &["l", "j", "k"]);
+        vec.insert(0, "m".into());
+        assert_eq!(vec.as_slice(), &["l", "j", "k"]);
+
+        vec.pop();
+        vec.fill_using(|| "n".into());
+        assert_eq!(vec.as_slice(), &["l", "j", "n"]);
+
+        let mut empty = StackVec::<[String; 0], Saturate>::new_with_policy();
+        empty.push("h".into());
+        empty.insert(0, "i".into());
+        empty.extend(Some("j".into()));
+        assert!(empty.is_empty());
+    }
+}
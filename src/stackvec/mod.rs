@@ -41,11 +41,68 @@ mod traits;
 ///   );
 ///   ```
 ///
+/// - or, within a `const` context such as a `static`, with the
+///   `const fn`s [`StackVec::new`] and [`StackVec::from_array`]:
+///   ```rust
+///   # use ::stackvec::prelude::*;
+///   use ::std::sync::Mutex;
+///
+///   static TABLE: Mutex<StackVec<[&str; 64]>>
+///       = Mutex::new(StackVec::new());
+///
+///   const PRIMES: StackVec<[u8; 4]>
+///       = StackVec::from_array([2, 3, 5, 7]);
+///
+///   TABLE.lock().unwrap().extend(vec!["Hello", "world"]);
+///   assert_eq!(TABLE.lock().unwrap().len(), 2);
+///   assert!(PRIMES.is_full());
+///   ```
+///
+/// # Overflow policy
+///
+/// What happens when an element is added to a full [`StackVec`] (with
+/// [`StackVec::push`], [`StackVec::insert`], or through
+/// [`Extend`][`iter::Extend`]) is determined by its second type parameter,
+/// an [`OverflowPolicy`]: by default,
+/// [`Ignore`][`::stackvec::policy::Ignore`], the element is silently
+/// dropped.
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// use ::stackvec::policy::Saturate;
+///
+/// // Keeps the 3 most recent readings.
+/// let mut readings = StackVec::<[f32; 3], Saturate>::new_with_policy();
+/// readings.extend(vec![0.5, 1.5, 2.5, 3.5]);
+/// assert_eq!(readings.as_slice(), &[1.5, 2.5, 3.5]);
+/// ```
+///
+/// # Layout
+///
+/// A [`StackVec`] is `#[repr(C)]`, with the (partially initialised) backing
+/// [`array`] as its first field, followed by its `len: usize`
+/// (the overflow policy is a zero-sized marker, which does not affect the
+/// layout).
+/// It can thus be shared with C code, where a `StackVec<[uint32_t; 8]>`
+/// is seen as:
+///
+/// ```c
+/// typedef struct {
+///     uint32_t array[8]; /* only the first `len` elements are initialised */
+///     size_t len;
+/// } StackVec_uint32_t_8;
+/// ```
+///
+/// See [`StackVec::from_raw_parts`], [`StackVec::into_raw_parts`] and
+/// [`StackVec::set_len`] to go through this representation.
+///
 /// [`array`]: https://doc.rust-lang.org/std/primitive.array.html
 /// [the `Array` trait]: `stackvec::Array`
-pub struct StackVec<A: Array> {
-    array: mem::ManuallyDrop<A>,
-    len: usize,
+#[repr(C)]
+pub struct StackVec<A: Array, P: OverflowPolicy = Ignore> {
+    pub(in crate) array: mem::MaybeUninit<A>,
+    pub(in crate) len: usize,
+    pub(in crate) policy: marker::PhantomData<P>,
 }
 
 impl<A: Array> Default for StackVec<A> {
@@ -53,28 +110,301 @@ impl<A: Array> Default for StackVec<A> {
     #[inline(always)]
     fn default () -> Self
     {
-        debug_assert!(Self::CAPACITY <= isize::MAX as usize);
-        StackVec {
-            len: 0,
-            array: mem::ManuallyDrop::new(unsafe { mem::uninitialized() }),
-        }
+        Self::new()
     }
 }
 
 impl<A: Array> StackVec<A> {
+    /// Constructor: new empty [`StackVec`]
+    ///
+    /// Unlike [`StackVec::default`](struct.StackVec.html#impl-Default),
+    /// it is a `const fn`, and can thus be used to initialise `const`s and
+    /// `static`s.
+    ///
+    /// It uses the default [`Ignore`][`crate::policy::Ignore`] policy (so
+    /// that `StackVec::new()` needs no annotation): with another policy,
+    /// use [`StackVec::new_with_policy`].
+    #[inline(always)]
+    pub const fn new () -> Self
+    {
+        Self::new_with_policy()
+    }
+
+    /// Constructor: a full [`StackVec`] made out of the given `array`.
+    ///
+    /// This is a `const fn` version of
+    /// [`StackVec::from`](struct.StackVec.html#impl-From%3CA%3E).
+    ///
+    /// It uses the default [`Ignore`][`crate::policy::Ignore`] policy: with
+    /// another policy, use [`StackVec::from_array_with_policy`].
+    #[inline(always)]
+    pub const fn from_array (
+        array: A,
+    ) -> Self
+    {
+        Self::from_array_with_policy(array)
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> StackVec<A, P> {
     /// The (statically) fixed capacity of the [`StackVec`]
     pub const CAPACITY: usize = A::LEN;
 
     /// The (statically) fixed capacity of the [`StackVec`]
     #[inline]
-    pub fn capacity (&self) -> usize { Self::CAPACITY }
+    pub const fn capacity (&self) -> usize { Self::CAPACITY }
+
+    /// Constructor: new empty [`StackVec`] with the [`OverflowPolicy`] `P`.
+    ///
+    /// Like `Vec::new_in`, this is [`StackVec::new`] for any policy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// use ::stackvec::policy::Panic;
+    ///
+    /// static STRICT: StackVec<[u8; 4], Panic> = StackVec::new_with_policy();
+    /// assert!(STRICT.is_empty());
+    ///
+    /// let mut strict = StackVec::<[u8; 4], Panic>::new_with_policy();
+    /// strict.extend(0 .. 4);
+    /// assert!(strict.is_full());
+    /// ```
+    #[inline(always)]
+    pub const fn new_with_policy () -> Self
+    {
+        debug_assert!(Self::CAPACITY <= isize::MAX as usize);
+        StackVec {
+            len: 0,
+            array: mem::MaybeUninit::uninit(),
+            policy: marker::PhantomData,
+        }
+    }
+
+    /// Constructor: a full [`StackVec`] made out of the given `array`, with
+    /// the [`OverflowPolicy`] `P`.
+    ///
+    /// This is [`StackVec::from_array`] for any policy.
+    #[inline(always)]
+    pub const fn from_array_with_policy (
+        array: A,
+    ) -> Self
+    {
+        StackVec {
+            array: mem::MaybeUninit::new(array),
+            len: A::LEN,
+            policy: marker::PhantomData,
+        }
+    }
 
-    /// Constructor: alias for [`Stackvec::default`](
-    /// struct.StackVec.html#impl-Default)
+    /// The number of elements in the [`StackVec`].
+    ///
+    /// Same as the `len()` of the [`StackVec`]'s slice, but usable in a
+    /// `const` context.
+    #[inline(always)]
+    pub const fn len (
+        self: &Self,
+    ) -> usize
+    {
+        self.len
+    }
+
+    /// Constructor: a [`StackVec`] made out of a (partially initialised)
+    /// backing `array` and the number `len` of its initialised elements.
+    ///
+    /// This is the inverse of [`StackVec::into_raw_parts`].
+    ///
+    /// # Safety
+    ///
+    /// - `len` must be less than or equal to [`StackVec::CAPACITY`];
+    ///
+    /// - the first `len` elements of `array` must be initialised.
+    ///
+    /// The elements past `len`, if any, are never read nor dropped.
     #[inline(always)]
-    pub fn new () -> Self
+    pub const unsafe fn from_raw_parts (
+        array: mem::MaybeUninit<A>,
+        len: usize,
+    ) -> Self
     {
-        Self::default()
+        debug_assert!(len <= Self::CAPACITY);
+        StackVec {
+            array,
+            len,
+            policy: marker::PhantomData,
+        }
+    }
+
+    /// Changes the [`OverflowPolicy`] of the [`StackVec`] (a no-op at
+    /// runtime).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// use ::stackvec::policy::Panic;
+    ///
+    /// let vec = StackVec::<[_; 4]>::from_iter(0 .. 10);
+    /// assert!(vec.is_full());
+    ///
+    /// let mut strict_vec = vec.with_policy::<Panic>();
+    /// strict_vec.pop();
+    /// strict_vec.push(42);
+    /// assert_eq!(strict_vec.as_slice(), &[0, 1, 2, 42]);
+    /// ```
+    #[inline]
+    pub fn with_policy<Q: OverflowPolicy> (
+        self: Self,
+    ) -> StackVec<A, Q>
+    {
+        let (array, len) = self.into_raw_parts();
+        unsafe { StackVec::from_raw_parts(array, len) }
+    }
+
+    /// Decomposes the [`StackVec`] into its (partially initialised) backing
+    /// array and the number of its initialised elements (its `len`).
+    ///
+    /// The elements are not dropped: the caller becomes responsible for them.
+    /// They can be given back to a [`StackVec`] with
+    /// [`StackVec::from_raw_parts`].
+    #[inline]
+    pub fn into_raw_parts (
+        self: Self,
+    ) -> (mem::MaybeUninit<A>, usize)
+    {
+        let slf = mem::ManuallyDrop::new(self);
+        (
+            unsafe { ptr::read(&slf.array) },
+            slf.len,
+        )
+    }
+
+    /// Forces the length of the [`StackVec`] to `new_len`.
+    ///
+    /// This is a low-level operation that maintains none of the invariants
+    /// of the type: elements past the new length are neither dropped
+    /// (when shrinking) nor initialised (when growing).
+    /// Prefer [`StackVec::truncate`], [`StackVec::clear`] or
+    /// [`Extend`][`iter::Extend`] when possible.
+    ///
+    /// It is mainly meant to be used after having initialised the spare
+    /// capacity of the [`StackVec`] through
+    /// [`StackVec::spare_capacity_mut`] or [`StackVec::as_mut_ptr`].
+    ///
+    /// # Safety
+    ///
+    /// - `new_len` must be less than or equal to [`StackVec::CAPACITY`];
+    ///
+    /// - the elements at `old_len .. new_len` must be initialised.
+    #[inline]
+    pub unsafe fn set_len (
+        self: &mut Self,
+        new_len: usize,
+    )
+    {
+        debug_assert!(new_len <= Self::CAPACITY);
+        self.len = new_len;
+    }
+
+    /// Returns a raw pointer to the [`StackVec`]'s buffer, _i.e._, to the first
+    /// (`0`-th) slot of the backing array, be it initialised or not.
+    ///
+    /// Unlike the `.as_ptr()` of the [`StackVec`]'s slice, the returned pointer
+    /// may be used to access the whole buffer, including its spare
+    /// capacity.
+    ///
+    /// The caller must ensure that the [`StackVec`] outlives the pointer this
+    /// function returns, and that the memory it points to is never written
+    /// to using this pointer (or any pointer derived from it). If you need to
+    /// mutate the contents of the buffer, use [`StackVec::as_mut_ptr`].
+    #[inline(always)]
+    pub const fn as_ptr (
+        self: &Self,
+    ) -> *const A::Item
+    {
+        self.array.as_ptr() as *const A::Item
+    }
+
+    /// Returns an unsafe mutable pointer to the [`StackVec`]'s buffer, _i.e._, to
+    /// the first (`0`-th) slot of the backing array, be it initialised or not.
+    ///
+    /// Unlike the `.as_mut_ptr()` of the [`StackVec`]'s slice, the returned
+    /// pointer may be used to access the whole buffer, including its spare
+    /// capacity (_e.g._, to initialise it before a call to
+    /// [`StackVec::set_len`]).
+    ///
+    /// The caller must ensure that the [`StackVec`] outlives the pointer this
+    /// function returns.
+    #[inline(always)]
+    pub fn as_mut_ptr (
+        self: &mut Self,
+    ) -> *mut A::Item
+    {
+        self.array.as_mut_ptr() as *mut A::Item
+    }
+
+    /// Returns the remaining spare capacity of the [`StackVec`] as a slice of
+    /// [`MaybeUninit<A::Item>`][`mem::MaybeUninit`].
+    ///
+    /// The returned slice can be used to fill the [`StackVec`] with data (_e.g._,
+    /// by reading from a file) before marking the data as initialised using
+    /// [`StackVec::set_len`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let mut buffer = StackVec::<[u8; 4096]>::new();
+    /// buffer.push_or_ignore(b'>');
+    ///
+    /// let spare = buffer.spare_capacity_mut();
+    /// assert_eq!(spare.len(), 4095);
+    /// // Fill in the first 5 spare slots (e.g., with a `read(2)`)
+    /// for (slot, &byte) in spare.iter_mut().zip(b"Hello") {
+    ///     *slot = ::std::mem::MaybeUninit::new(byte);
+    /// }
+    ///
+    /// unsafe {
+    ///     // Mark the first 5 spare slots as initialised
+    ///     buffer.set_len(1 + 5);
+    /// }
+    /// assert_eq!(buffer.as_slice(), b">Hello");
+    /// ```
+    #[inline]
+    pub fn spare_capacity_mut (
+        self: &mut Self,
+    ) -> &mut [mem::MaybeUninit<A::Item>]
+    {
+        self.split_at_spare_mut().1
+    }
+
+    /// Returns the [`StackVec`]'s content as a slice of initialised elements,
+    /// along with its remaining spare capacity as a slice of
+    /// [`MaybeUninit<A::Item>`][`mem::MaybeUninit`].
+    ///
+    /// This is like [`StackVec::spare_capacity_mut`], but still lets the
+    /// already initialised elements be accessed (_e.g._, to fill the spare
+    /// capacity from them).
+    #[inline]
+    pub fn split_at_spare_mut (
+        self: &mut Self,
+    ) -> (&mut [A::Item], &mut [mem::MaybeUninit<A::Item>])
+    {
+        let len = self.len;
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            (
+                slice::from_raw_parts_mut(
+                    ptr,
+                    len,
+                ),
+                slice::from_raw_parts_mut(
+                    ptr.add(len) as *mut mem::MaybeUninit<A::Item>,
+                    Self::CAPACITY - len,
+                ),
+            )
+        }
     }
 
     /// Attempts to push a `value` into the [`StackVec`].
@@ -98,6 +428,67 @@ impl<A: Array> StackVec<A> {
         }
     }
 
+    /// Attempts to append all the elements of `iterable` to the
+    /// [`StackVec`].
+    ///
+    /// Contrary to [`Extend`][`iter::Extend`], no element is ever lost: if
+    /// the [`StackVec`] gets full while the iterator still yields elements,
+    /// it fails returning the first rejected element and the rest of the
+    /// iterator, wrapped in an [`ExtendOverflow`] error.
+    ///
+    /// If the lower bound of the iterator's
+    /// [`size_hint`][`Iterator::size_hint`] exceeds the spare capacity, it
+    /// fails right away, without consuming anything.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let words = "lorem ipsum dolor sit amet".split(' ');
+    ///
+    /// let mut first = StackVec::<[&str; 3]>::new();
+    /// let overflow = first.try_extend(words).unwrap_err();
+    /// assert_eq!(first.as_slice(), &["lorem", "ipsum", "dolor"]);
+    /// assert_eq!(overflow.rejected(), Some(&"sit"));
+    ///
+    /// // Resume where the first one stopped
+    /// let mut second = StackVec::<[&str; 3]>::new();
+    /// assert!(second.try_extend(overflow.into_remaining()).is_ok());
+    /// assert_eq!(second.as_slice(), &["sit", "amet"]);
+    /// ```
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let mut vec = StackVec::<[u8; 4]>::from_iter(0 .. 2);
+    /// // `0 .. 3` is known to be too long: nothing is consumed
+    /// let (rejected, remaining) = vec.try_extend(0 .. 3).unwrap_err().into_inner();
+    /// assert!(rejected.is_none());
+    /// assert_eq!(remaining, 0 .. 3);
+    /// assert_eq!(vec.as_slice(), &[0, 1]);
+    /// ```
+    #[inline]
+    pub fn try_extend<Iterable: IntoIterator<Item = A::Item>> (
+        self: &mut Self,
+        iterable: Iterable,
+    ) -> Result<(), ExtendOverflow<Iterable::IntoIter>>
+    {
+        let mut iterator = iterable.into_iter();
+        debug_assert!(self.len <= Self::CAPACITY);
+        if iterator.size_hint().0 > Self::CAPACITY - self.len {
+            return Err(ExtendOverflow::new(None, iterator));
+        };
+        while self.len < Self::CAPACITY {
+            match iterator.next() {
+                Some(value) => unsafe { self.push_unchecked(value) },
+                None => return Ok(()),
+            };
+        };
+        match iterator.next() {
+            Some(rejected) => Err(ExtendOverflow::new(Some(rejected), iterator)),
+            None => Ok(()),
+        }
+    }
+
     /// Pushes the given `value` into the [`StackVec`] if there is room for it,
     /// else it does nothing.
     ///
@@ -137,13 +528,101 @@ impl<A: Array> StackVec<A> {
     {
         debug_assert!(self.len < Self::CAPACITY); // implicit assertion
         ptr::write(
-            self.array.as_mut_ptr()
-                .offset(self.len as isize),
+            self.as_mut_ptr()
+                .add(self.len),
             value,
         );
         self.len += 1;
     }
 
+    /// Appends `value` to the back of the [`StackVec`].
+    ///
+    /// What happens when the [`StackVec`] is full depends on its
+    /// [`OverflowPolicy`] `P`, which also determines the return type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// use ::stackvec::policy;
+    ///
+    /// let mut vec = StackVec::<[_; 2]>::new();
+    /// vec.push(0);
+    /// vec.push(1);
+    /// vec.push(2); // ignored
+    /// assert_eq!(vec.as_slice(), &[0, 1]);
+    ///
+    /// let mut vec = StackVec::<[_; 2], policy::Error>::new_with_policy();
+    /// assert!(vec.push(0).is_ok());
+    /// assert!(vec.push(1).is_ok());
+    /// assert_eq!(vec.push(2).unwrap_err().0, 2);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use ::stackvec::prelude::*;
+    /// use ::stackvec::policy::Panic;
+    ///
+    /// let mut vec = StackVec::<[_; 2], Panic>::from_array_with_policy([0, 1]);
+    /// vec.push(2); // panics
+    /// ```
+    #[inline]
+    pub fn push (
+        self: &mut Self,
+        value: A::Item,
+    ) -> P::Output<A::Item>
+    {
+        debug_assert!(self.len <= Self::CAPACITY);
+        if self.len < Self::CAPACITY {
+            unsafe { self.push_unchecked(value) };
+            P::added()
+        } else {
+            P::push_overflow(self, value)
+        }
+    }
+
+    /// Inserts `value` at position `index`, shifting all the elements after
+    /// it to the right.
+    ///
+    /// What happens when the [`StackVec`] is full depends on its
+    /// [`OverflowPolicy`] `P`, which also determines the return type.
+    ///
+    /// # Panics
+    ///
+    /// If `index > len`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let mut vec = StackVec::<[_; 4]>::from_iter(vec!['a', 'c']);
+    /// vec.insert(1, 'b');
+    /// vec.insert(3, 'd');
+    /// vec.insert(0, '_'); // ignored
+    /// assert_eq!(vec.as_slice(), &['a', 'b', 'c', 'd']);
+    /// ```
+    #[inline]
+    pub fn insert (
+        self: &mut Self,
+        index: usize,
+        value: A::Item,
+    ) -> P::Output<A::Item>
+    {
+        let len = self.len;
+        assert!(index <= len,
+            "insertion index (is {}) should be <= len (is {})", index, len,
+        );
+        if len == Self::CAPACITY {
+            return P::insert_overflow(self, index, value);
+        };
+        unsafe {
+            let at_index = self.as_mut_ptr().add(index);
+            ptr::copy(at_index, at_index.add(1), len - index);
+            ptr::write(at_index, value);
+            self.len = len + 1;
+        }
+        P::added()
+    }
+
     /// Removes `value` and returns `Some(value)`, where `value` is the last
     /// element of the non-empty [`StackVec`], else it just returns `None`.
     #[inline]
@@ -157,8 +636,8 @@ impl<A: Array> StackVec<A> {
             Some(
                 unsafe {
                     ptr::read(
-                        self.array.as_ptr()
-                            .offset(self.len as isize),
+                        self.as_ptr()
+                            .add(self.len),
                     )
                 }
             )
@@ -185,8 +664,8 @@ impl<A: Array> StackVec<A> {
             self.len = new_len;
             unsafe {
                 ptr::drop_in_place(
-                    self.array.as_mut_ptr()
-                        .offset(new_len as isize)
+                    self.as_mut_ptr()
+                        .add(new_len)
                 );
             };
         };
@@ -205,13 +684,18 @@ impl<A: Array> StackVec<A> {
 
     /// Extracts a slice containing the entire [`StackVec`].
     ///
-    /// Equivalent to `&stackvec[..]`.
+    /// Equivalent to `&stackvec[..]`, but usable in a `const` context.
     #[inline]
-    pub fn as_slice (
+    pub const fn as_slice (
         self: &Self,
     ) -> &[A::Item]
     {
-        &* self
+        unsafe {
+            slice::from_raw_parts(
+                self.as_ptr(),
+                self.len,
+            )
+        }
     }
 
     /// Extracts a mutable slice of the entire [`StackVec`].
@@ -228,7 +712,7 @@ impl<A: Array> StackVec<A> {
     /// Returns `true` iff the [`StackVec`] is empty
     /// (`self.len() == 0`)
     #[inline]
-    pub fn is_empty (
+    pub const fn is_empty (
         self: &Self,
     ) -> bool
     {
@@ -238,7 +722,7 @@ impl<A: Array> StackVec<A> {
     /// Returns `true` iff the [`StackVec`] is full
     /// (`self.len() == self.capacity()`)
     #[inline]
-    pub fn is_full (
+    pub const fn is_full (
         self: &Self,
     ) -> bool
     {
@@ -271,7 +755,7 @@ impl<A: Array> StackVec<A> {
     /// let vec = StackVec::<[String; 64]>::
     ///     from_iter(
     ///         ["Hello", "world"]
-    ///             .into_iter()
+    ///             .iter().cloned()
     ///             .map(String::from)
     ///     ).fill_using(|| s.clone());
     /// ```
@@ -284,13 +768,203 @@ impl<A: Array> StackVec<A> {
         factory: impl FnMut() -> A::Item,
     )
     {
+        // Not polled past the capacity, whatever the overflow policy
+        let spare = Self::CAPACITY - self.len;
         self.extend(
             iter::repeat_with(factory)
+                .take(spare)
         )
     }
+
+    /// Moves the elements into a [`StackVec`] of greater (or equal)
+    /// capacity, with the same [`OverflowPolicy`].
+    ///
+    /// That `B::LEN >= A::LEN` is checked at compile time (when
+    /// monomorphizing). To move the elements into a [`StackVec`] of lower
+    /// capacity, see [`StackVec::try_narrow`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let small = StackVec::<[String; 16]>::from_iter(
+    ///     ["Hello", "world"].iter().map(|&s| String::from(s))
+    /// );
+    /// let mut big: StackVec<[String; 64]> = small.widen();
+    /// assert_eq!(big.as_slice(), &["Hello", "world"]);
+    /// big.fill_using(String::new);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use ::stackvec::prelude::*;
+    /// let big = StackVec::<[u8; 64]>::new();
+    /// let small: StackVec<[u8; 16]> = big.widen();
+    /// ```
+    #[inline]
+    pub fn widen<B> (
+        self: Self,
+    ) -> StackVec<B, P>
+    where
+        B: Array<Item = A::Item>,
+    {
+        const {
+            assert!(
+                B::LEN >= A::LEN,
+                "Cannot widen a StackVec into a lower capacity",
+            );
+        };
+        unsafe { self.into_capacity_unchecked() }
+    }
+
+    /// Moves the elements into a [`StackVec`] of another (_e.g._, lower)
+    /// capacity, with the same [`OverflowPolicy`], provided they fit.
+    /// Otherwise, `self` is given back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let big = StackVec::<[u8; 64]>::from_iter(0 .. 20);
+    ///
+    /// let big = big.try_narrow::<[u8; 16]>().unwrap_err();
+    /// let small = big.try_narrow::<[u8; 32]>().unwrap();
+    /// assert_eq!(small.len(), 20);
+    /// ```
+    #[inline]
+    pub fn try_narrow<B> (
+        self: Self,
+    ) -> Result<StackVec<B, P>, Self>
+    where
+        B: Array<Item = A::Item>,
+    {
+        if self.len <= B::LEN {
+            Ok(unsafe { self.into_capacity_unchecked() })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Moves the elements into a [`StackVec`] of another capacity.
+    ///
+    /// # Safety
+    ///
+    /// `self.len()` must not exceed `B::LEN`.
+    #[inline]
+    unsafe fn into_capacity_unchecked<B> (
+        self: Self,
+    ) -> StackVec<B, P>
+    where
+        B: Array<Item = A::Item>,
+    {
+        debug_assert!(self.len <= B::LEN);
+        let mut slf = mem::ManuallyDrop::new(self);
+        let mut stackvec = StackVec::<B, P>::new_with_policy();
+        ptr::copy_nonoverlapping(
+            slf.as_mut_ptr(),
+            stackvec.as_mut_ptr(),
+            slf.len,
+        );
+        stackvec.set_len(slf.len);
+        stackvec
+    }
+
+    /// Moves the elements out, grouped by `B::LEN` into `B` [`Array`]s.
+    ///
+    /// This is [`.array_chunks()`][`IteratorExt::array_chunks`] on the
+    /// [`IntoIter`]: once the full chunks have been yielded, the trailing
+    /// elements can be retrieved as a [`StackVec<B>`][`StackVec`] with
+    /// [`.into_remainder()`][`ArrayChunks::into_remainder`].
+    ///
+    /// # Panics
+    ///
+    /// If `B::LEN` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let message = StackVec::<[u8; 1024]>::from_iter(0 .. 200);
+    ///
+    /// let mut frames = message.into_array_chunks::<[u8; 64]>();
+    /// assert_eq!(frames.len(), 3);
+    /// for frame in frames.by_ref() {
+    ///     assert_eq!(frame.len(), 64);
+    /// }
+    /// let last_frame = frames.into_remainder();
+    /// assert_eq!(last_frame.as_slice(), &[192, 193, 194, 195, 196, 197, 198, 199]);
+    /// ```
+    #[inline]
+    pub fn into_array_chunks<B> (
+        self: Self,
+    ) -> ArrayChunks<IntoIter<A, P>, B>
+    where
+        B: Array<Item = A::Item>,
+    {
+        ArrayChunks::new(self.into_iter())
+    }
 }
 
-impl<A: Array> StackVec<A>
+impl<A: Array, P: OverflowPolicy> StackVec<A, P>
+where
+    A::Item: Array,
+{
+    /// Moves the elements of the inner [`Array`]s into a single
+    /// [`StackVec`] with the same [`OverflowPolicy`], in order.
+    ///
+    /// The capacity of the returned [`StackVec`] must be able to hold all
+    /// the inner elements of a full `self` (_i.e._,
+    /// `B::LEN >= A::LEN * A::Item::LEN`), which is checked at compile time
+    /// (when monomorphizing).
+    ///
+    /// This is the inverse of
+    /// [`.into_array_chunks()`][`StackVec::into_array_chunks`] (without
+    /// its remainder).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let mut frames = StackVec::<[[u8; 2]; 8]>::new();
+    /// frames.extend(vec![[0, 1], [2, 3], [4, 5]]);
+    ///
+    /// let message: StackVec<[u8; 16]> = frames.flatten();
+    /// assert_eq!(message.as_slice(), &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn flatten<B> (
+        self: Self,
+    ) -> StackVec<B, P>
+    where
+        B: Array<Item = <A::Item as Array>::Item>,
+    {
+        const {
+            assert!(
+                B::LEN >= A::LEN * <A::Item as Array>::LEN,
+                "Cannot flatten a StackVec into a lower capacity",
+            );
+        };
+        let chunk_len = <A::Item as Array>::LEN;
+        let slf = mem::ManuallyDrop::new(self);
+        let mut stackvec = StackVec::<B, P>::new_with_policy();
+        for (i, chunk) in slf.iter().enumerate() {
+            // Copy chunk by chunk, since an over-aligned chunk may have
+            // trailing padding.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    chunk.as_ptr(),
+                    stackvec.as_mut_ptr().add(i * chunk_len),
+                    chunk_len,
+                );
+            }
+        };
+        unsafe {
+            stackvec.set_len(slf.len * chunk_len);
+        }
+        stackvec
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> StackVec<A, P>
 where
     A::Item: Copy,
 {
@@ -302,7 +976,7 @@ where
     /// let vec = StackVec::<[&'static str; 64]>::
     ///     from_iter(
     ///         ["Hello", "world"]
-    ///             .into_iter()
+    ///             .iter().cloned()
     ///     ).fill_with("!");
     /// ```
     #[inline]
@@ -311,13 +985,15 @@ where
         value: A::Item,
     )
     {
+        // Not polled past the capacity, whatever the overflow policy
+        let spare = Self::CAPACITY - self.len;
         self.extend(
-            iter::repeat(value)
+            iter::repeat_n(value, spare)
         )
     }
 }
 
-impl<A: Array> Drop for StackVec<A> {
+impl<A: Array, P: OverflowPolicy> Drop for StackVec<A, P> {
     #[inline]
     fn drop (
         self: &mut Self,
@@ -327,7 +1003,7 @@ impl<A: Array> Drop for StackVec<A> {
     }
 }
 
-impl<A: Array> ops::Deref for StackVec<A> {
+impl<A: Array, P: OverflowPolicy> ops::Deref for StackVec<A, P> {
     type Target = [A::Item];
 
     #[inline]
@@ -335,16 +1011,11 @@ impl<A: Array> ops::Deref for StackVec<A> {
         self: &Self,
     ) -> &Self::Target
     {
-        unsafe {
-            slice::from_raw_parts(
-                self.array.as_ptr(),
-                self.len,
-            )
-        }
+        self.as_slice()
     }
 }
 
-impl<A: Array> ops::DerefMut for StackVec<A> {
+impl<A: Array, P: OverflowPolicy> ops::DerefMut for StackVec<A, P> {
     #[inline]
     fn deref_mut (
         self: &mut Self,
@@ -352,14 +1023,14 @@ impl<A: Array> ops::DerefMut for StackVec<A> {
     {
         unsafe {
             slice::from_raw_parts_mut(
-                self.array.as_mut_ptr(),
+                self.as_mut_ptr(),
                 self.len,
             )
         }
     }
 }
 
-impl<A: Array> fmt::Debug for StackVec<A>
+impl<A: Array, P: OverflowPolicy> fmt::Debug for StackVec<A, P>
 where
     A::Item: fmt::Debug,
 {
@@ -368,12 +1039,12 @@ where
         stream: &mut fmt::Formatter,
     ) -> fmt::Result
     {
-        try!(fmt::Display::fmt("[", stream));
+        fmt::Display::fmt("[", stream)?;
         let mut iterator = self.iter();
         if let Some(first) = iterator.next() {
-            try!(fmt::Debug::fmt(first, stream));
+            fmt::Debug::fmt(first, stream)?;
             for x in iterator {
-                try!(write!(stream, ", {:?}", x));
+                write!(stream, ", {:?}", x)?;
             };
         };
         fmt::Display::fmt("]", stream)
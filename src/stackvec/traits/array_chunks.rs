@@ -0,0 +1,238 @@
+use super::*;
+
+/// An iterator over the elements of another iterator, grouped by
+/// [`Array::LEN`] into [`Array`]s (taken by value).
+///
+/// This `struct` is created by the
+/// [`.array_chunks()`][`IteratorExt::array_chunks`] method
+/// (provided by the [`IteratorExt`] trait).
+///
+/// When the number of elements is not a multiple of [`Array::LEN`], the
+/// trailing elements that do not fill a chunk are not yielded, but can be
+/// retrieved (as a [`StackVec`]) with [`ArrayChunks::into_remainder`].
+pub struct ArrayChunks<I, A>
+where
+    I: Iterator,
+    A: Array<Item = I::Item>,
+{
+    iterator: iter::Fuse<I>,
+    remainder: StackVec<A>,
+    back_remainder_stripped: bool,
+}
+
+impl<I, A> ArrayChunks<I, A>
+where
+    I: Iterator,
+    A: Array<Item = I::Item>,
+{
+    #[inline]
+    pub(in crate) fn new (
+        iterator: I,
+    ) -> Self
+    {
+        assert!(A::LEN != 0, "Chunks cannot be empty");
+        ArrayChunks {
+            iterator: iterator.fuse(),
+            remainder: StackVec::new(),
+            back_remainder_stripped: false,
+        }
+    }
+
+    /// Consumes the adapter and returns the trailing elements that did not
+    /// fill a whole chunk.
+    ///
+    /// It is only meaningful once the iterator has been exhausted (or, for
+    /// a [`DoubleEndedIterator`], once [`next_back`][
+    /// `DoubleEndedIterator::next_back`] has been called); before that,
+    /// the returned [`StackVec`] is empty.
+    #[inline]
+    pub fn into_remainder (
+        self: Self,
+    ) -> StackVec<A>
+    {
+        self.remainder
+    }
+
+    #[inline]
+    fn set_remainder (
+        self: &mut Self,
+        remainder: StackVec<A>,
+    )
+    {
+        debug_assert!(!remainder.is_full());
+        if !remainder.is_empty() {
+            debug_assert!(self.remainder.is_empty());
+            self.remainder = remainder;
+        };
+    }
+}
+
+impl<I, A> Iterator for ArrayChunks<I, A>
+where
+    I: Iterator,
+    A: Array<Item = I::Item>,
+{
+    type Item = A;
+
+    #[inline]
+    fn next (
+        self: &mut Self,
+    ) -> Option<A>
+    {
+        match self.iterator.next_array() {
+            Ok(array) => Some(array),
+            Err(error) => {
+                self.set_remainder(error.into_inner());
+                None
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint (
+        self: &Self,
+    ) -> (usize, Option<usize>)
+    {
+        let (lower, upper) = self.iterator.size_hint();
+        (lower / A::LEN, upper.map(|upper| upper / A::LEN))
+    }
+}
+
+impl<I, A> DoubleEndedIterator for ArrayChunks<I, A>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    A: Array<Item = I::Item>,
+{
+    #[inline]
+    fn next_back (
+        self: &mut Self,
+    ) -> Option<A>
+    {
+        if !self.back_remainder_stripped {
+            self.back_remainder_stripped = true;
+            let remainder_len = self.iterator.len() % A::LEN;
+            let mut remainder = StackVec::<A>::new();
+            remainder.extend(
+                self.iterator.by_ref().rev().take(remainder_len)
+            );
+            remainder.reverse();
+            self.set_remainder(remainder);
+        };
+        let mut chunk = StackVec::<A>::new();
+        chunk.extend(
+            self.iterator.by_ref().rev().take(A::LEN)
+        );
+        chunk.reverse();
+        match chunk.try_into() {
+            Ok(array) => Some(array),
+            Err(error) => {
+                // Unreachable with a well-behaved `ExactSizeIterator`
+                self.set_remainder(error.into_inner());
+                None
+            },
+        }
+    }
+}
+
+impl<I, A> ExactSizeIterator for ArrayChunks<I, A>
+where
+    I: ExactSizeIterator,
+    A: Array<Item = I::Item>,
+{
+    #[inline]
+    fn len (
+        self: &Self,
+    ) -> usize
+    {
+        self.iterator.len() / A::LEN
+    }
+}
+
+impl<I, A> iter::FusedIterator for ArrayChunks<I, A>
+where
+    I: Iterator,
+    A: Array<Item = I::Item>,
+{}
+
+impl<I, A> Clone for ArrayChunks<I, A>
+where
+    I: Iterator + Clone,
+    A: Array<Item = I::Item>,
+    A::Item: Clone,
+{
+    fn clone (
+        self: &Self,
+    ) -> Self
+    {
+        ArrayChunks {
+            iterator: self.iterator.clone(),
+            remainder: self.remainder.clone(),
+            back_remainder_stripped: self.back_remainder_stripped,
+        }
+    }
+}
+
+impl<I, A> fmt::Debug for ArrayChunks<I, A>
+where
+    I: Iterator + fmt::Debug,
+    A: Array<Item = I::Item>,
+    A::Item: fmt::Debug,
+{
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        stream
+            .debug_struct("ArrayChunks")
+            .field("iterator", &self.iterator)
+            .field("remainder", &self.remainder)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(unstable_name_collisions)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn forward ()
+    {
+        let mut chunks = (0 .. 8).array_chunks::<[_; 3]>();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.next(), Some([0, 1, 2]));
+        assert_eq!(chunks.next(), Some([3, 4, 5]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.into_remainder().as_slice(), &[6, 7]);
+    }
+
+    #[test]
+    fn backward ()
+    {
+        let mut chunks =
+            (0 .. 11)
+            .map(|x| x.to_string())
+            .array_chunks::<[_; 3]>();
+        assert_eq!(chunks.next_back().unwrap(), ["6", "7", "8"]);
+        assert_eq!(chunks.next().unwrap(), ["0", "1", "2"]);
+        assert_eq!(chunks.next_back().unwrap(), ["3", "4", "5"]);
+        assert!(chunks.next_back().is_none());
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.into_remainder().as_slice(), &["9", "10"]);
+    }
+
+    #[test]
+    fn exact_multiple ()
+    {
+        let chunks = (0 .. 6).array_chunks::<[_; 2]>();
+        assert_eq!(
+            chunks.clone().rev().collect::<Vec<_>>(),
+            [[4, 5], [2, 3], [0, 1]],
+        );
+        let mut chunks = chunks;
+        assert_eq!(chunks.by_ref().count(), 3);
+        assert!(chunks.into_remainder().is_empty());
+    }
+}
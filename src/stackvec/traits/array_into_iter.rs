@@ -1,5 +1,8 @@
 use super::*;
 
+/// Only for the default [`Ignore`][`::stackvec::policy::Ignore`] policy
+/// (so that `StackVec::from(array)` needs no annotation): with another
+/// policy, use [`StackVec::from_array`].
 impl<A: Array> From<A> for StackVec<A>
 {
     #[inline(always)]
@@ -7,10 +10,7 @@ impl<A: Array> From<A> for StackVec<A>
         array: A,
     ) -> StackVec<A>
     {
-        StackVec {
-            array: mem::ManuallyDrop::new(array),
-            len: A::LEN,
-        }
+        StackVec::from_array(array)
     }
 }
 
@@ -18,15 +18,30 @@ impl<A: Array> From<A> for StackVec<A>
 /// method to almost seamlessly use [`array`]s
 /// as [by-owned-value iterators][`IntoIterator`].
 ///
+/// # Compatibility with Rust 1.53 and onwards
+///
+/// Since Rust 1.53, [`array`]s are themselves [`IntoIterator`] by value,
+/// so this trait is no longer exported by the
+/// [`prelude`][`crate::prelude`]. Beware that `array.into_iter()` only
+/// iterates by value in the 2021 edition (and onwards): in the 2015 and 2018
+/// editions, it autorefs and iterates by reference, and no longer iterates
+/// by value through a glob import of the prelude, as it used to.
+///
+/// To get a by-value iterator in any edition, and in particular to get a
+/// [`stackvec::IntoIter`][`crate::IntoIter`] (_e.g._, to use
+/// [`.into_remaining()`][`crate::IntoIter::into_remaining`]), use
+/// [`IntoIter::from(array)`][`crate::IntoIter`], or call this trait's
+/// method with the fully qualified syntax.
+///
 /// # Example
 /// ```rust
-/// # use ::stackvec::prelude::*;
+/// use ::stackvec::ArrayIntoIter;
+///
 /// let array: [_; 2] = [
 ///     vec![1, 2, 3, 4],
 ///     vec![5, 6],
 /// ];
-/// let flattened: Vec<u8> = array
-///                             .into_iter()
+/// let flattened: Vec<u8> = ArrayIntoIter::into_iter(array)
 ///                             .flatten()
 ///                             .collect();
 /// assert_eq!(flattened, &[1, 2, 3, 4, 5, 6]);
@@ -41,7 +56,7 @@ pub trait ArrayIntoIter: Array {
         self: Self,
     ) -> crate::IntoIter<Self>
     {
-        StackVec::from(self).into_iter()
+        crate::IntoIter::from(self)
     }
 }
 impl<A: Array> ArrayIntoIter for A {}
@@ -49,6 +64,7 @@ impl<A: Array> ArrayIntoIter for A {}
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
+    use crate::ArrayIntoIter;
 
     #[derive(PartialEq, Eq, Hash)]
     struct NoClone;
@@ -58,7 +74,7 @@ mod tests {
     {
         let array = [NoClone, NoClone, NoClone, NoClone];
         let set = ::std::collections::HashSet::<NoClone>::from_iter(
-            array.into_iter()
+            ArrayIntoIter::into_iter(array)
         );
         assert!(!set.is_empty());
     }
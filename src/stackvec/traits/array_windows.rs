@@ -0,0 +1,202 @@
+use super::*;
+
+/// An iterator over the overlapping windows of [`Array::LEN`] consecutive
+/// elements of another iterator, yielded as (cloned) [`Array`]s.
+///
+/// This `struct` is created by the
+/// [`.array_windows()`][`IteratorExt::array_windows`] method
+/// (provided by the [`IteratorExt`] trait).
+///
+/// The last [`Array::LEN`] elements are kept within a [`StackVec`] used as
+/// a ring buffer, so no heap allocation is involved.
+pub struct ArrayWindows<I, A>
+where
+    I: Iterator,
+    A: Array<Item = I::Item>,
+    A::Item: Clone,
+{
+    iterator: iter::Fuse<I>,
+    buffer: StackVec<A>,
+    head: usize, // index of the oldest element, once the buffer is full
+}
+
+impl<I, A> ArrayWindows<I, A>
+where
+    I: Iterator,
+    A: Array<Item = I::Item>,
+    A::Item: Clone,
+{
+    #[inline]
+    pub(in crate) fn new (
+        iterator: I,
+    ) -> Self
+    {
+        assert!(A::LEN != 0, "Windows cannot be empty");
+        ArrayWindows {
+            iterator: iterator.fuse(),
+            buffer: StackVec::new(),
+            head: 0,
+        }
+    }
+}
+
+impl<I, A> Iterator for ArrayWindows<I, A>
+where
+    I: Iterator,
+    A: Array<Item = I::Item>,
+    A::Item: Clone,
+{
+    type Item = A;
+
+    #[inline]
+    fn next (
+        self: &mut Self,
+    ) -> Option<A>
+    {
+        if self.buffer.is_full() {
+            let value = self.iterator.next()?;
+            self.buffer[self.head] = value;
+            self.head = (self.head + 1) % A::LEN;
+        } else {
+            let missing = A::LEN - self.buffer.len();
+            self.buffer.extend(
+                self.iterator.by_ref().take(missing)
+            );
+            if !self.buffer.is_full() {
+                return None;
+            };
+        };
+        let (newest, oldest) = self.buffer.split_at(self.head);
+        let window: StackVec<A> =
+            oldest.iter()
+                .chain(newest)
+                .cloned()
+                .collect();
+        Some(
+            window
+                .try_into()
+                .unwrap_or_else(|_| unreachable!())
+        )
+    }
+
+    #[inline]
+    fn size_hint (
+        self: &Self,
+    ) -> (usize, Option<usize>)
+    {
+        let (lower, upper) = self.iterator.size_hint();
+        let missing = A::LEN - self.buffer.len();
+        let windows = |remaining: usize| if missing == 0 {
+            remaining
+        } else {
+            // `remaining + 1 - missing`, without overflowing on infinite
+            // sources (`remaining == usize::MAX`)
+            remaining.saturating_sub(missing - 1)
+        };
+        (windows(lower), upper.map(windows))
+    }
+}
+
+impl<I, A> ExactSizeIterator for ArrayWindows<I, A>
+where
+    I: ExactSizeIterator,
+    A: Array<Item = I::Item>,
+    A::Item: Clone,
+{}
+
+impl<I, A> iter::FusedIterator for ArrayWindows<I, A>
+where
+    I: Iterator,
+    A: Array<Item = I::Item>,
+    A::Item: Clone,
+{}
+
+impl<I, A> Clone for ArrayWindows<I, A>
+where
+    I: Iterator + Clone,
+    A: Array<Item = I::Item>,
+    A::Item: Clone,
+{
+    fn clone (
+        self: &Self,
+    ) -> Self
+    {
+        ArrayWindows {
+            iterator: self.iterator.clone(),
+            buffer: self.buffer.clone(),
+            head: self.head,
+        }
+    }
+}
+
+impl<I, A> fmt::Debug for ArrayWindows<I, A>
+where
+    I: Iterator + fmt::Debug,
+    A: Array<Item = I::Item>,
+    A::Item: Clone + fmt::Debug,
+{
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        stream
+            .debug_struct("ArrayWindows")
+            .field("iterator", &self.iterator)
+            .field("buffer", &self.buffer)
+            .field("head", &self.head)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn windows ()
+    {
+        let mut windows = (0 .. 5).array_windows::<[_; 3]>();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows.next(), Some([0, 1, 2]));
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows.next(), Some([1, 2, 3]));
+        assert_eq!(windows.next(), Some([2, 3, 4]));
+        assert_eq!(windows.len(), 0);
+        assert_eq!(windows.next(), None);
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn not_enough_elements ()
+    {
+        let mut windows = (0 .. 2).array_windows::<[_; 3]>();
+        assert_eq!(windows.size_hint(), (0, Some(0)));
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn non_copy_elements ()
+    {
+        let windows: Vec<[String; 2]> =
+            "abcd".chars()
+                .map(String::from)
+                .array_windows()
+                .collect();
+        assert_eq!(windows, [["a", "b"], ["b", "c"], ["c", "d"]]);
+    }
+
+    #[test]
+    fn infinite_source ()
+    {
+        let windows = ::std::iter::repeat(1_u32).array_windows::<[_; 2]>();
+        assert_eq!(windows.size_hint(), (usize::MAX - 1, None));
+        let windows: Vec<[u32; 2]> = windows.take(3).collect();
+        assert_eq!(windows, [[1, 1]; 3]);
+
+        let mut counter = 0_u8 ..;
+        let mut windows = counter.by_ref().array_windows::<[_; 3]>();
+        assert_eq!(windows.next(), Some([0, 1, 2]));
+        assert_eq!(windows.size_hint(), (usize::MAX, None));
+    }
+}
@@ -1,31 +1,35 @@
 use super::*;
 
-impl<A: Array> iter::Extend<A::Item> for StackVec<A> {
+/// The elements that do not fit within the [`StackVec::CAPACITY`] are
+/// handled according to the [`OverflowPolicy`] (by default, they are
+/// silently ignored).
+///
+/// Since a [`StackVec`] is itself [`IntoIterator`], this is also how the
+/// elements of a [`StackVec`] of another capacity are moved into this one:
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// let mut all = StackVec::<[String; 8]>::new();
+/// let some = StackVec::<[String; 2]>::from_iter(
+///     ["a", "b"].iter().map(|&s| String::from(s))
+/// );
+/// all.extend(some);
+/// assert_eq!(all.as_slice(), &["a", "b"]);
+/// ```
+impl<A: Array, P: OverflowPolicy> iter::Extend<A::Item> for StackVec<A, P> {
     #[inline]
     fn extend<Iterable: IntoIterator<Item = A::Item>> (
         self: &mut Self,
         iterable: Iterable,
     )
     {
-        // This is currently the most optimized `extend` implementation,
-        // branching-prediction-wise
-        let mut len = self.len;
-        debug_assert!(len <= Self::CAPACITY);
-        if len == Self::CAPACITY {
-            return
-        };
+        let mut iterator = iterable.into_iter();
+        let ptr = self.as_mut_ptr();
         unsafe {
-            for value in iterable {
-                debug_assert!(len < Self::CAPACITY);
-                ptr::write(
-                    self.array.as_mut_ptr()
-                        .offset(len as isize),
-                    value,
-                );
-                len += 1;
-                self.len = len;
-                if len == Self::CAPACITY { break };
-            };
+            extend_within(ptr, &mut self.len, Self::CAPACITY, &mut iterator);
+        }
+        if self.len == Self::CAPACITY {
+            P::extend_overflow(self, iterator);
         };
 
         // // This version was less optimized:
@@ -56,6 +60,14 @@ impl<A: Array> iter::Extend<A::Item> for StackVec<A> {
     }
 }
 
+/// The elements that do not fit within the [`StackVec::CAPACITY`] are
+/// silently ignored: use [`.try_collect()`][`TryCollect::try_collect`] to
+/// get an error instead.
+///
+/// Only for the default [`Ignore`][`crate::policy::Ignore`] policy (so that
+/// `StackVec::from_iter(...)` needs no annotation, like [`Vec`]'s): with
+/// another [`OverflowPolicy`], [`Extend`][`iter::Extend`] a
+/// [`StackVec::new_with_policy`] instead.
 impl<A: Array> iter::FromIterator<A::Item> for StackVec<A> {
     #[inline(always)]
     fn from_iter<Iterable: IntoIterator<Item = A::Item>> (
@@ -67,3 +79,126 @@ impl<A: Array> iter::FromIterator<A::Item> for StackVec<A> {
         slf
     }
 }
+
+/// Pulls elements out of `iterator` into the uninitialised slots
+/// `*len .. capacity` of the buffer at `ptr`, until either runs out.
+///
+/// The capacity is checked once per chunk rather than once per element:
+/// each chunk is as long as the lower bound of the iterator's `size_hint`
+/// (capped by the spare capacity), and is written with a `for_each`, which
+/// lets the iterator drive the loop (_e.g._, one loop per half of a
+/// [`Chain`][`iter::Chain`]). For iterators with an accurate `size_hint`
+/// (all the [`ExactSizeIterator`]s and `TrustedLen` ones), this means a
+/// single tight loop. Iterators with no lower bound are pulled from one
+/// element at a time.
+///
+/// `*len` is only written back once, at the end (or when unwinding, if the
+/// iterator panics).
+///
+/// The iterator is never polled past the capacity.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of `capacity` elements, and
+/// `*len <= capacity` must hold.
+#[inline(always)]
+unsafe fn extend_within<I: Iterator> (
+    ptr: *mut I::Item,
+    len: &mut usize,
+    capacity: usize,
+    iterator: &mut I,
+)
+{
+    debug_assert!(*len <= capacity);
+    let mut len = SetLenOnDrop { local_len: *len, len };
+    loop {
+        let spare = capacity - len.local_len;
+        if spare == 0 { break };
+        let chunk = cmp::min(iterator.size_hint().0, spare);
+        if chunk == 0 {
+            match iterator.next() {
+                Some(value) => {
+                    ptr::write(ptr.add(len.local_len), value);
+                    len.local_len += 1;
+                },
+                None => break,
+            };
+        } else {
+            let start = len.local_len;
+            iterator.by_ref().take(chunk).for_each(|value| unsafe {
+                // `local_len < start + chunk <= capacity`
+                ptr::write(ptr.add(len.local_len), value);
+                len.local_len += 1;
+            });
+            if len.local_len - start < chunk { break }; // exhausted
+        };
+    }
+}
+
+/// Writes the local length back to the `len` field when dropped, so that the
+/// elements written so far are not leaked if the iterator panics.
+struct SetLenOnDrop<'len> {
+    len: &'len mut usize,
+    local_len: usize,
+}
+
+impl Drop for SetLenOnDrop<'_> {
+    #[inline(always)]
+    fn drop (
+        self: &mut Self,
+    )
+    {
+        *self.len = self.local_len;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use ::std::rc::Rc;
+
+    /// Claims more elements than it yields.
+    struct Liar<I>(I);
+
+    impl<I: Iterator> Iterator for Liar<I> {
+        type Item = I::Item;
+
+        fn next (
+            self: &mut Self,
+        ) -> Option<I::Item>
+        {
+            self.0.next()
+        }
+
+        fn size_hint (
+            self: &Self,
+        ) -> (usize, Option<usize>)
+        {
+            (usize::MAX, None)
+        }
+    }
+
+    #[test]
+    fn wrong_size_hint ()
+    {
+        let vec = StackVec::<[_; 8]>::from_iter(Liar(0 .. 3));
+        assert_eq!(vec.as_slice(), &[0, 1, 2]);
+        let vec = StackVec::<[_; 8]>::from_iter((0 .. 3).filter(|_| true));
+        assert_eq!(vec.as_slice(), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn panicking_iterator_does_not_leak ()
+    {
+        let rc = Rc::new(());
+        let result = ::std::panic::catch_unwind(|| {
+            let mut vec = StackVec::<[Rc<()>; 8]>::new();
+            vec.extend((0 .. 8).map(|i| {
+                assert!(i < 5, "boom");
+                rc.clone()
+            }));
+        });
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+/// Conversion out of a [`StackVec`], used by the methods of [`IteratorExt`]
+/// that fill several buffers at once (_e.g._,
+/// [`.try_unzip()`][`IteratorExt::try_unzip`]), so that the caller may pick
+/// the kind of each output.
+///
+/// It is implemented for:
+///
+///   - [`StackVec`] itself, which never fails;
+///
+///   - [`CopyStackVec`], which never fails either;
+///
+///   - [`Array`]s, which fail with an [`IncompleteArrayError`] when the
+///     [`StackVec`] is not full.
+pub trait FromStackVec: Sized {
+    /// The backing [`Array`] of the [`StackVec`] to convert from.
+    type Array: Array;
+
+    /// The error type returned when the conversion fails.
+    type Error: ::std::error::Error;
+
+    /// Performs the conversion.
+    fn from_stackvec (
+        stackvec: StackVec<Self::Array>,
+    ) -> Result<Self, Self::Error>;
+}
+
+impl<A: Array> FromStackVec for A {
+    type Array = A;
+
+    type Error = IncompleteArrayError<A>;
+
+    #[inline(always)]
+    fn from_stackvec (
+        stackvec: StackVec<A>,
+    ) -> Result<Self, Self::Error>
+    {
+        stackvec.try_into()
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> FromStackVec for StackVec<A, P> {
+    type Array = A;
+
+    type Error = UnreachableError;
+
+    #[inline(always)]
+    fn from_stackvec (
+        stackvec: StackVec<A>,
+    ) -> Result<Self, Self::Error>
+    {
+        Ok(stackvec.with_policy())
+    }
+}
+
+impl<A: Array + Copy> FromStackVec for CopyStackVec<A> {
+    type Array = A;
+
+    type Error = UnreachableError;
+
+    #[inline(always)]
+    fn from_stackvec (
+        stackvec: StackVec<A>,
+    ) -> Result<Self, Self::Error>
+    {
+        Ok(stackvec.into())
+    }
+}
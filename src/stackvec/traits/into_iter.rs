@@ -4,31 +4,153 @@ use super::*;
 ///
 /// This `struct` is created by the `into_iter` method (provided
 /// by the [`IntoIterator`] trait).
-pub struct Iter<A: Array> {
-    stackvec: StackVec<A>,
+///
+/// It can also be created out of an [`Array`], with
+/// [`IntoIter::from`][`From::from`].
+pub struct Iter<A: Array, P: OverflowPolicy = Ignore> {
+    array: mem::MaybeUninit<A>,
+
+    // Invariant: the elements at `start .. end` (and only those) are
+    // initialised and owned by the iterator; they are the remaining ones.
     start: usize,
+    end: usize,
+
+    // Given back by `into_remaining`.
+    policy: marker::PhantomData<P>,
 }
 
-impl<A: Array> Drop for Iter<A> {
+impl<A: Array, P: OverflowPolicy> Iter<A, P> {
+    /// Read-only view of the remaining elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let mut iterator = StackVec::<[_; 4]>::from_iter(0 .. 4).into_iter();
+    /// iterator.next();
+    /// iterator.next_back();
+    /// assert_eq!(iterator.as_slice(), &[1, 2]);
+    /// ```
+    #[inline]
+    pub fn as_slice (
+        self: &Self,
+    ) -> &[A::Item]
+    {
+        unsafe {
+            slice::from_raw_parts(
+                (self.array.as_ptr() as *const A::Item)
+                    .add(self.start),
+                self.end - self.start,
+            )
+        }
+    }
+
+    /// Read-write view of the remaining elements.
+    #[inline]
+    pub fn as_mut_slice (
+        self: &mut Self,
+    ) -> &mut [A::Item]
+    {
+        unsafe {
+            slice::from_raw_parts_mut(
+                (self.array.as_mut_ptr() as *mut A::Item)
+                    .add(self.start),
+                self.end - self.start,
+            )
+        }
+    }
+
+    /// Gets the remaining elements back within a [`StackVec`] (moved to its
+    /// front), with the [`OverflowPolicy`] of the [`StackVec`] iterated
+    /// over.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let mut words = StackVec::<[String; 4]>::from_iter(
+    ///     "Hello , world !".split(' ').map(String::from)
+    /// ).into_iter();
+    /// let greeting = words.next().unwrap();
+    /// assert_eq!(greeting, "Hello");
+    ///
+    /// let mut rest = words.into_remaining();
+    /// assert_eq!(rest.as_slice(), &[",", "world", "!"]);
+    /// rest.push_or_ignore(greeting);
+    /// assert!(rest.is_full());
+    /// ```
+    #[inline]
+    pub fn into_remaining (
+        self: Self,
+    ) -> StackVec<A, P>
+    {
+        let mut slf = mem::ManuallyDrop::new(self);
+        let len = slf.end - slf.start;
+        unsafe {
+            let ptr = slf.array.as_mut_ptr() as *mut A::Item;
+            ptr::copy(ptr.add(slf.start), ptr, len);
+            StackVec::from_raw_parts(ptr::read(&slf.array), len)
+        }
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> Drop for Iter<A, P> {
     fn drop (
         self: &mut Self,
     )
     {
-        let len = self.stackvec.len;
-        self.stackvec.len = 0;
-        for i in self.start .. len {
-            unsafe {
-                ptr::drop_in_place(
-                    self.stackvec
-                        .array.as_mut_ptr()
-                        .offset(i as isize)
-                );
-            };
-        };
+        unsafe {
+            ptr::drop_in_place(self.as_mut_slice());
+        }
+    }
+}
+
+impl<A: Array> From<A> for Iter<A> {
+    #[inline]
+    fn from (
+        array: A,
+    ) -> Self
+    {
+        Iter {
+            array: mem::MaybeUninit::new(array),
+            start: 0,
+            end: A::LEN,
+            policy: marker::PhantomData,
+        }
     }
 }
 
-impl<A: Array> Iterator for Iter<A> {
+impl<A: Array, P: OverflowPolicy> Clone for Iter<A, P>
+where
+    A::Item: Clone,
+{
+    fn clone (
+        self: &Self,
+    ) -> Self
+    {
+        let mut stackvec = StackVec::<A, P>::new_with_policy();
+        stackvec.extend(self.as_slice().iter().cloned());
+        stackvec.into_iter()
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> fmt::Debug for Iter<A, P>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt (
+        self: &Self,
+        stream: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        stream
+            .debug_tuple("IntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> Iterator for Iter<A, P> {
     type Item = A::Item;
 
     #[inline]
@@ -36,14 +158,13 @@ impl<A: Array> Iterator for Iter<A> {
         self: &mut Self,
     ) -> Option<Self::Item>
     {
-        let start = self.start;
-        if start < self.stackvec.len {
+        if self.start < self.end {
+            let start = self.start;
             self.start = start + 1;
             Some(unsafe {
                 ptr::read(
-                    self.stackvec
-                        .array.as_ptr()
-                        .offset(start as isize),
+                    (self.array.as_ptr() as *const A::Item)
+                        .add(start),
                 )
             })
         } else {
@@ -56,23 +177,23 @@ impl<A: Array> Iterator for Iter<A> {
         self: &Self,
     ) -> (usize, Option<usize>)
     {
-        let size = self.stackvec.len - self.start;
+        let size = self.end - self.start;
         (size, Some(size))
     }
 }
 
-impl<A: Array> iter::FusedIterator for Iter<A> {}
+impl<A: Array, P: OverflowPolicy> iter::FusedIterator for Iter<A, P> {}
 
 #[cfg(feature = "nightly")]
-unsafe impl<A: Array> iter::TrustedLen for Iter<A> {}
+unsafe impl<A: Array, P: OverflowPolicy> iter::TrustedLen for Iter<A, P> {}
 
-impl<A: Array> ExactSizeIterator for Iter<A> {
+impl<A: Array, P: OverflowPolicy> ExactSizeIterator for Iter<A, P> {
     #[inline]
     fn len (
         self: &Self,
     ) -> usize
     {
-        self.stackvec.len - self.start
+        self.end - self.start
     }
 
     #[cfg(feature = "nightly")]
@@ -81,46 +202,73 @@ impl<A: Array> ExactSizeIterator for Iter<A> {
         self: &Self,
     ) -> bool
     {
-        self.stackvec.len == self.start
+        self.end == self.start
     }
 }
 
-impl<A: Array> DoubleEndedIterator for Iter<A> {
+impl<A: Array, P: OverflowPolicy> DoubleEndedIterator for Iter<A, P> {
     #[inline]
     fn next_back (
         self: &mut Self,
     ) -> Option<Self::Item>
     {
-        let last = self.stackvec.len.saturating_sub(1);
-        if self.start <= last {
-            self.stackvec.len = last;
+        if self.start < self.end {
+            let last = self.end - 1;
+            self.end = last;
             Some(unsafe {
                 ptr::read(
-                    self.stackvec
-                        .array.as_ptr()
-                        .offset(last as isize),
+                    (self.array.as_ptr() as *const A::Item)
+                        .add(last),
                 )
             })
         } else {
             None
         }
-
     }
 }
 
-impl<A: Array> IntoIterator for StackVec<A> {
+impl<A: Array, P: OverflowPolicy> IntoIterator for StackVec<A, P> {
     type Item = A::Item;
 
-    type IntoIter = Iter<A>;
+    type IntoIter = Iter<A, P>;
 
     #[inline(always)]
     fn into_iter (
         self: Self,
     ) -> Self::IntoIter
     {
+        let (array, len) = self.into_raw_parts();
         Iter {
-            stackvec: self,
+            array,
             start: 0,
+            end: len,
+            policy: marker::PhantomData,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoIter;
+
+    #[test]
+    fn clone_and_debug_show_the_remaining_elements ()
+    {
+        let mut iterator = IntoIter::from(["a", "b", "c"].map(String::from));
+        assert_eq!(iterator.next_back().unwrap(), "c");
+        let mut clone = iterator.clone();
+        assert_eq!(iterator.next().unwrap(), "a");
+        assert_eq!(format!("{:?}", iterator), r#"IntoIter(["b"])"#);
+        assert_eq!(format!("{:?}", clone), r#"IntoIter(["a", "b"])"#);
+        clone.as_mut_slice()[1].push('!');
+        assert_eq!(clone.collect::<Vec<_>>(), ["a", "b!"]);
+    }
+
+    #[test]
+    fn into_remaining_of_an_exhausted_iterator ()
+    {
+        let mut iterator = IntoIter::from([0_u8; 3]);
+        iterator.by_ref().for_each(drop);
+        assert!(iterator.into_remaining().is_empty());
+    }
+}
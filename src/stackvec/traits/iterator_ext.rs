@@ -0,0 +1,321 @@
+use super::*;
+
+/// [`Iterator`] [extension trait](
+/// https://users.rust-lang.org/t/supertraits-vs-generic-implementations/21266)
+/// providing [`StackVec`]-based methods to [`Iterator`]s.
+pub trait IteratorExt: Iterator + Sized {
+    /// Pulls the next [`Array::LEN`] elements out of the iterator, and
+    /// returns them as an [`Array`].
+    ///
+    /// Unlike [`try_collect`][`TryCollect::try_collect`], it does not consume
+    /// the iterator, which is left positioned right after the pulled
+    /// elements.
+    ///
+    /// If the iterator runs out of elements before filling the [`Array`],
+    /// the pulled elements are given back within the
+    /// [`IncompleteArrayError`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let mut bytes = b"\x02\x00Hi".iter().cloned();
+    ///
+    /// let header: [u8; 2] = bytes.next_array().unwrap();
+    /// let len = u16::from_le_bytes(header) as usize;
+    /// let body: Vec<u8> = bytes.by_ref().take(len).collect();
+    /// assert_eq!(body, b"Hi");
+    ///
+    /// let error = bytes.next_array::<[u8; 2]>().unwrap_err();
+    /// assert!(error.into_inner().is_empty());
+    /// ```
+    #[inline]
+    fn next_array<A> (
+        self: &mut Self,
+    ) -> Result<A, IncompleteArrayError<A>>
+    where
+        A: Array<Item = Self::Item>,
+    {
+        self.next_stackvec::<A>()
+            .try_into()
+    }
+
+    /// Pulls up to [`Array::LEN`] elements out of the iterator, and
+    /// returns them as a [`StackVec`].
+    ///
+    /// The iterator is left positioned right after the pulled elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let mut numbers = 0 .. 5;
+    ///
+    /// let first = numbers.next_stackvec::<[_; 3]>();
+    /// assert_eq!(first.as_slice(), &[0, 1, 2]);
+    /// let second = numbers.next_stackvec::<[_; 3]>();
+    /// assert_eq!(second.as_slice(), &[3, 4]);
+    /// assert!(numbers.next_stackvec::<[_; 3]>().is_empty());
+    /// ```
+    #[inline]
+    fn next_stackvec<A> (
+        self: &mut Self,
+    ) -> StackVec<A>
+    where
+        A: Array<Item = Self::Item>,
+    {
+        self.by_ref()
+            .take(A::LEN)
+            .collect()
+    }
+
+    /// Returns an iterator over the elements of `self`, grouped by
+    /// [`Array::LEN`] into [`Array`]s (taken by value).
+    ///
+    /// When the number of elements is not a multiple of [`Array::LEN`], the
+    /// trailing elements that do not fill a chunk are not yielded, but can
+    /// be retrieved (as a [`StackVec`]) with
+    /// [`.into_remainder()`][`ArrayChunks::into_remainder`].
+    ///
+    /// The returned iterator is double-ended when `self` is double-ended
+    /// and [exact-size][`ExactSizeIterator`].
+    ///
+    /// Note that the standard library has an unstable method with the same
+    /// name, which triggers the `unstable_name_collisions` lint: if that is
+    /// an issue, use the fully qualified syntax
+    /// `IteratorExt::array_chunks::<_, A>(iterator)` instead.
+    ///
+    /// # Panics
+    ///
+    /// If [`Array::LEN`] is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let samples = (0 .. 10).map(|x| x as f32);
+    ///
+    /// let mut frames = samples.array_chunks::<[f32; 4]>();
+    /// assert_eq!(frames.next(), Some([0., 1., 2., 3.]));
+    /// assert_eq!(frames.next(), Some([4., 5., 6., 7.]));
+    /// assert_eq!(frames.next(), None);
+    /// assert_eq!(frames.into_remainder().as_slice(), &[8., 9.]);
+    /// ```
+    #[inline]
+    fn array_chunks<A> (
+        self: Self,
+    ) -> ArrayChunks<Self, A>
+    where
+        A: Array<Item = Self::Item>,
+    {
+        ArrayChunks::new(self)
+    }
+
+    /// Returns an iterator over the overlapping windows of [`Array::LEN`]
+    /// consecutive elements of `self`, yielded as (cloned) [`Array`]s.
+    ///
+    /// The first window is yielded once [`Array::LEN`] elements have been
+    /// pulled; from then on, each element pulled yields a new window.
+    /// If `self` has fewer than [`Array::LEN`] elements, no window is
+    /// yielded.
+    ///
+    /// No heap allocation is involved.
+    ///
+    /// # Panics
+    ///
+    /// If [`Array::LEN`] is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let readings = vec![1., 2., 6., 3.];
+    ///
+    /// let moving_average: Vec<f64> =
+    ///     readings
+    ///         .into_iter()
+    ///         .array_windows::<[f64; 2]>()
+    ///         .map(|[a, b]| (a + b) / 2.)
+    ///         .collect();
+    /// assert_eq!(moving_average, [1.5, 4., 4.5]);
+    /// ```
+    #[inline]
+    fn array_windows<A> (
+        self: Self,
+    ) -> ArrayWindows<Self, A>
+    where
+        A: Array<Item = Self::Item>,
+        Self::Item: Clone,
+    {
+        ArrayWindows::new(self)
+    }
+
+    /// Splits an iterator of pairs into two collections, each backed by a
+    /// [`StackVec`]: the outputs may be [`StackVec`]s, [`CopyStackVec`]s, or
+    /// [`Array`]s (see [`FromStackVec`]).
+    ///
+    /// If a pair is yielded while either side is already full, it fails with
+    /// an [`UnzipError::Overflow`], which gives back the elements collected
+    /// so far and the rejected pair. Otherwise, if a side cannot be
+    /// converted (_e.g._, too few elements to make an [`Array`]), it fails
+    /// with [`UnzipError::Left`] or [`UnzipError::Right`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let pairs = vec![("x", 1.), ("y", 2.), ("z", 3.)];
+    ///
+    /// let (names, values) = pairs
+    ///     .into_iter()
+    ///     .try_unzip::<StackVec<[&str; 4]>, [f64; 3]>()
+    ///     .unwrap();
+    /// assert_eq!(names.as_slice(), &["x", "y", "z"]);
+    /// assert_eq!(values, [1., 2., 3.]);
+    /// ```
+    fn try_unzip<L, R> (
+        self: Self,
+    ) -> Result<(L, R), UnzipError<L, R>>
+    where
+        L: FromStackVec,
+        R: FromStackVec,
+        Self: Iterator<Item = (
+            <L::Array as Array>::Item,
+            <R::Array as Array>::Item,
+        )>,
+    {
+        let mut left = StackVec::<L::Array>::new();
+        let mut right = StackVec::<R::Array>::new();
+        for (x, y) in self {
+            if left.is_full() || right.is_full() {
+                return Err(UnzipError::Overflow {
+                    left,
+                    right,
+                    rejected: (x, y),
+                });
+            };
+            unsafe {
+                left.push_unchecked(x);
+                right.push_unchecked(y);
+            }
+        };
+        let left = L::from_stackvec(left).map_err(UnzipError::Left)?;
+        let right = R::from_stackvec(right).map_err(UnzipError::Right)?;
+        Ok((left, right))
+    }
+
+    /// Partitions the elements of the iterator into two [`StackVec`]s:
+    /// the ones satisfying the `predicate`, and the other ones.
+    ///
+    /// If an element belongs to an already full partition, it fails with a
+    /// [`PartitionOverflowError`], which gives back both partitions and the
+    /// rejected element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// let (evens, odds) = (0 .. 10)
+    ///     .try_partition::<[_; 5]>(|&x| x % 2 == 0)
+    ///     .unwrap();
+    /// assert_eq!(evens.as_slice(), &[0, 2, 4, 6, 8]);
+    /// assert_eq!(odds.as_slice(), &[1, 3, 5, 7, 9]);
+    /// ```
+    fn try_partition<A> (
+        self: Self,
+        mut predicate: impl FnMut(&Self::Item) -> bool,
+    ) -> Result<(StackVec<A>, StackVec<A>), PartitionOverflowError<A>>
+    where
+        A: Array<Item = Self::Item>,
+    {
+        let mut matching = StackVec::<A>::new();
+        let mut rest = StackVec::<A>::new();
+        for value in self {
+            let partition =
+                if predicate(&value) { &mut matching } else { &mut rest };
+            if partition.is_full() {
+                return Err(
+                    PartitionOverflowError::new(matching, rest, value)
+                );
+            };
+            unsafe { partition.push_unchecked(value) }
+        };
+        Ok((matching, rest))
+    }
+}
+impl<T: Iterator + Sized> IteratorExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn next_array_does_not_consume_the_rest ()
+    {
+        let mut numbers = (0 .. 10).map(|x| x.to_string());
+        let first: [String; 4] = numbers.next_array().unwrap();
+        let second: [String; 4] = numbers.next_array().unwrap();
+        assert_eq!(first[3], "3");
+        assert_eq!(second[0], "4");
+
+        let error = numbers.next_array::<[String; 4]>().unwrap_err();
+        assert_eq!(error.missing(), 2);
+        assert_eq!(error.into_inner().as_slice(), &["8", "9"]);
+        assert!(numbers.next().is_none());
+    }
+
+    #[test]
+    fn next_stackvec_pulls_at_most_capacity ()
+    {
+        let mut numbers = 0 .. 5;
+        assert!(numbers.next_stackvec::<[_; 0]>().is_empty());
+        assert_eq!(numbers.next_stackvec::<[_; 2]>().as_slice(), &[0, 1]);
+        assert_eq!(numbers.next(), Some(2));
+    }
+
+    #[test]
+    fn try_unzip_errors ()
+    {
+        use crate::error::UnzipError;
+
+        let pairs = || (0 .. 4).map(|x| (x, x.to_string()));
+        match pairs().try_unzip::<[_; 3], StackVec<[String; 8]>>() {
+            Err(UnzipError::Overflow { left, right, rejected }) => {
+                assert_eq!(left.as_slice(), &[0, 1, 2]);
+                assert_eq!(right.as_slice(), &["0", "1", "2"]);
+                assert_eq!(rejected, (3, "3".to_string()));
+            },
+            _ => panic!("expected an overflow"),
+        };
+        match pairs().try_unzip::<StackVec<[_; 8]>, [String; 5]>() {
+            Err(UnzipError::Right(error)) => assert_eq!(error.missing(), 1),
+            _ => panic!("expected an incomplete right side"),
+        };
+        let (left, right) =
+            pairs()
+                .try_unzip::<CopyStackVec<[_; 4]>, [String; 4]>()
+                .unwrap();
+        assert_eq!(left.as_slice(), &[0, 1, 2, 3]);
+        assert_eq!(right[3], "3");
+    }
+
+    #[test]
+    fn try_partition_overflows_on_either_side ()
+    {
+        let error = (0 .. 10)
+                        .try_partition::<[_; 4]>(|&x| x >= 2)
+                        .unwrap_err();
+        let (matching, rest, rejected) = error.into_inner();
+        assert_eq!(matching.as_slice(), &[2, 3, 4, 5]);
+        assert_eq!(rest.as_slice(), &[0, 1]);
+        assert_eq!(rejected, 6);
+
+        let (matching, rest) =
+            "a1b2".chars()
+                .map(String::from)
+                .try_partition::<[_; 2]>(|s| s.parse::<u8>().is_ok())
+                .unwrap();
+        assert_eq!(matching.as_slice(), &["1", "2"]);
+        assert_eq!(rest.as_slice(), &["a", "b"]);
+    }
+}
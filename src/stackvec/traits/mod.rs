@@ -1,25 +1,73 @@
 use super::*;
 
-impl<A: Array> Eq for StackVec<A>
+impl<A: Array, P: OverflowPolicy> Eq for StackVec<A, P>
 where
     A::Item : Eq,
 {}
 
-impl<A: Array> PartialEq for StackVec<A>
+/// [`StackVec`]s of different capacities can be compared with each other.
+///
+/// Hence the type of the other [`StackVec`] must be known:
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// let vec = StackVec::<[u8; 4]>::new();
+/// assert!(vec == StackVec::<[_; 4]>::new()); // `StackVec::new()` is ambiguous
+/// assert!(vec == StackVec::<[_; 8]>::new());
+/// ```
+impl<A, P, B, Q> PartialEq<StackVec<B, Q>> for StackVec<A, P>
 where
-    A::Item : PartialEq,
+    A: Array,
+    P: OverflowPolicy,
+    B: Array,
+    Q: OverflowPolicy,
+    A::Item : PartialEq<B::Item>,
 {
     #[inline(always)]
     fn eq (
         self: &Self,
-        other: &Self,
+        other: &StackVec<B, Q>,
     ) -> bool
     {
         self.as_slice().eq(other.as_slice())
     }
 }
 
-impl<A: Array> hash::Hash for StackVec<A>
+/// [`StackVec`]s of different capacities can be compared with each other
+/// ([lexicographically][`Ord#lexicographical-comparison`]).
+impl<A, P, B, Q> PartialOrd<StackVec<B, Q>> for StackVec<A, P>
+where
+    A: Array,
+    P: OverflowPolicy,
+    B: Array<Item = A::Item>,
+    Q: OverflowPolicy,
+    A::Item : PartialOrd,
+{
+    #[inline(always)]
+    fn partial_cmp (
+        self: &Self,
+        other: &StackVec<B, Q>,
+    ) -> Option<cmp::Ordering>
+    {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> Ord for StackVec<A, P>
+where
+    A::Item : Ord,
+{
+    #[inline(always)]
+    fn cmp (
+        self: &Self,
+        other: &Self,
+    ) -> cmp::Ordering
+    {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<A: Array, P: OverflowPolicy> hash::Hash for StackVec<A, P>
 where
     A::Item : hash::Hash,
 {
@@ -32,7 +80,7 @@ where
     }
 }
 
-impl<A: Array> Clone for StackVec<A>
+impl<A: Array, P: OverflowPolicy> Clone for StackVec<A, P>
 where
     A::Item : Clone,
 {
@@ -40,7 +88,9 @@ where
         self: &Self,
     ) -> Self
     {
-        self.iter().cloned().collect()
+        let mut stackvec = Self::new_with_policy();
+        stackvec.extend(self.iter().cloned());
+        stackvec
     }
 }
 
@@ -57,3 +107,15 @@ mod try_into;
 
 pub use self::try_from_iter::*;
 mod try_from_iter;
+
+pub use self::from_stackvec::*;
+mod from_stackvec;
+
+pub use self::iterator_ext::*;
+mod iterator_ext;
+
+pub use self::array_chunks::*;
+mod array_chunks;
+
+pub use self::array_windows::*;
+mod array_windows;
@@ -15,6 +15,15 @@ use self::try_into::TryInto;
 /// is rarely called explicitly, and is instead
 /// used through [`Iterator`]'s [`try_collect`] method.
 ///
+/// Homogeneous tuples (up to 12 elements) are supported through the array
+/// of the same length, so that the collected elements can be destructured:
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// let (host, port) = "localhost:8080".split(':').try_collect().unwrap();
+/// assert_eq!(port, "8080");
+/// ```
+///
 /// See also: [`IntoIterator`].
 ///
 /// [`try_collect`]: `::stackvec::traits::TryCollect::try_collect`
@@ -64,34 +73,260 @@ pub trait TryCollect: Iterator + Sized {
     {
         Collection::try_from_iter(self)
     }
+
+    /// Strict version of [`try_collect`][`TryCollect::try_collect`] into an
+    /// [`Array`]: it also fails if there are more elements than what the
+    /// [`Array`] can hold, instead of silently ignoring the surplus.
+    ///
+    /// The first surplus element (if any) is given back within the error,
+    /// but the rest of the iterator is dropped: pass
+    /// [`.by_ref()`][`Iterator::by_ref`] to keep it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// use ::stackvec::error::ExactCollectError;
+    ///
+    /// let array: [_; 3] = (0 .. 3).try_collect_exact().unwrap();
+    /// assert_eq!(array, [0, 1, 2]);
+    ///
+    /// match (0 .. 20).try_collect_exact::<[_; 15]>() {
+    ///     Err(ExactCollectError::TooMany { array, surplus }) => {
+    ///         assert_eq!(array[14], 14);
+    ///         assert_eq!(surplus, 15);
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// match (0 .. 10).try_collect_exact::<[_; 15]>() {
+    ///     Err(ExactCollectError::TooFew(error)) => {
+    ///         assert_eq!(error.missing(), 5);
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[inline]
+    fn try_collect_exact<A> (
+        self: Self,
+    ) -> Result<A, ExactCollectError<A>>
+    where
+        A: Array<Item = Self::Item>,
+    {
+        let mut iterator = self;
+        let stackvec = StackVec::<A>::from_iter(
+            iterator.by_ref().take(A::LEN)
+        );
+        match stackvec.try_into() {
+            Err(error) => Err(ExactCollectError::TooFew(error)),
+            Ok(array) => match iterator.next() {
+                None => Ok(array),
+                Some(surplus) => Err(ExactCollectError::TooMany {
+                    array,
+                    surplus,
+                }),
+            },
+        }
+    }
+
+    /// Short-circuiting version of
+    /// [`try_collect`][`TryCollect::try_collect`] into an [`Array`], for
+    /// iterators of [`Result`]s or [`Option`]s (see [`TryItem`]).
+    ///
+    /// It stops at the first `Err(error)` (or `None`), dropping the elements
+    /// collected so far, and returns `error` (or [`NoneError`]) within a
+    /// [`ShortCircuitError::Item`].
+    ///
+    /// Otherwise, it fails with a [`ShortCircuitError::Incomplete`] when
+    /// there are not enough elements to fill the [`Array`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ::stackvec::prelude::*;
+    /// use ::stackvec::error::ShortCircuitError;
+    ///
+    /// fn parse (record: &str) -> Result<[u16; 3], ShortCircuitError<
+    ///     ::std::num::ParseIntError,
+    ///     [u16; 3],
+    /// >>
+    /// {
+    ///     record.split(',').map(str::parse).try_collect_ok()
+    /// }
+    ///
+    /// assert_eq!(parse("1,2,3").unwrap(), [1, 2, 3]);
+    /// match parse("1,x,3") {
+    ///     Err(ShortCircuitError::Item(_)) => {},
+    ///     _ => unreachable!(),
+    /// }
+    /// match parse("1,2") {
+    ///     Err(ShortCircuitError::Incomplete(_)) => {},
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[inline]
+    fn try_collect_ok<A> (
+        self: Self,
+    ) -> Result<A, ShortCircuitError<<Self::Item as TryItem>::Error, A>>
+    where
+        Self::Item: TryItem,
+        A: Array<Item = <Self::Item as TryItem>::Value>,
+    {
+        let mut stackvec = StackVec::<A>::new();
+        for item in self.take(A::LEN) {
+            match item.into_result() {
+                Ok(value) => unsafe {
+                    // Safety: there are at most `A::LEN` items
+                    stackvec.push_unchecked(value)
+                },
+                Err(error) => return Err(ShortCircuitError::Item(error)),
+            };
+        };
+        stackvec
+            .try_into()
+            .map_err(ShortCircuitError::Incomplete)
+    }
 }
 impl<T: Iterator + Sized> TryCollect for T {}
 
-// pub enum Unreachable {}
-
-// impl fmt::Display for Unreachable {
-//     fn fmt (&self, _: &mut fmt::Formatter) -> fmt::Result {
-//         unreachable!()
-//     }
-// }
-// impl fmt::Debug for Unreachable {
-//     fn fmt (&self, _: &mut fmt::Formatter) -> fmt::Result {
-//         unreachable!()
-//     }
-// }
-// impl ::std::error::Error for Unreachable {}
-
-// impl<Item, T: iter::FromIterator<Item>> TryFromIterator<Item> for T {
-//     type Error = Unreachable;
-
-//     #[inline(always)]
-//     fn try_from_iter<Iterable: IntoIterator<Item = Item>> (
-//         iterable: Iterable,
-//     ) -> Result<Self, Self::Error>
-//     {
-//         Ok(Self::from_iter(iterable))
-//     }
-// }
+/// Items that may short-circuit a
+/// [`.try_collect_ok()`][`TryCollect::try_collect_ok`]: [`Result`]s
+/// and [`Option`]s.
+pub trait TryItem {
+    /// The type of the successful value (`T` for a `Result<T, E>`).
+    type Value;
+
+    /// The type of the error (`E` for a `Result<T, E>`).
+    type Error;
+
+    /// Extracts the successful value, or the error.
+    fn into_result (
+        self: Self,
+    ) -> Result<Self::Value, Self::Error>;
+}
+
+impl<T, E> TryItem for Result<T, E> {
+    type Value = T;
+
+    type Error = E;
+
+    #[inline(always)]
+    fn into_result (
+        self: Self,
+    ) -> Result<T, E>
+    {
+        self
+    }
+}
+
+impl<T> TryItem for Option<T> {
+    type Value = T;
+
+    type Error = NoneError;
+
+    #[inline(always)]
+    fn into_result (
+        self: Self,
+    ) -> Result<T, NoneError>
+    {
+        self.ok_or(NoneError)
+    }
+}
+
+/// Wrapper to make any [`FromIterator`] type usable as a
+/// [`TryFromIterator`] one, with an [`UnreachableError`].
+///
+/// This lets generic code that relies on
+/// [`.try_collect()`][`TryCollect::try_collect`] collect into any
+/// collection, be it fallible (_e.g._, an [`Array`]) or not.
+///
+/// The collections of the standard library are already [`TryFromIterator`]
+/// (with an [`UnreachableError`]) and thus do not need this wrapper; it
+/// is only needed for other [`FromIterator`] types, since a blanket
+/// implementation would conflict with the [`Array`] one.
+///
+/// # Example
+///
+/// ```rust
+/// # use ::stackvec::prelude::*;
+/// use ::stackvec::Unfallible;
+///
+/// fn parse<C: TryFromIterator<u8>> (digits: &str) -> Result<C, C::Error>
+/// {
+///     digits.bytes().map(|b| b - b'0').try_collect()
+/// }
+///
+/// let array: [u8; 3] = parse("123").unwrap();
+/// let vec = parse::<Vec<u8>>("123").unwrap_or_else(|e| e.unreachable());
+/// let Unfallible(stackvec) =
+///     parse::<Unfallible<StackVec<[u8; 8]>>>("123")
+///         .unwrap_or_else(|e| e.unreachable());
+/// assert_eq!(array, vec.as_slice());
+/// assert_eq!(array, stackvec.as_slice());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Unfallible<Collection>(pub Collection);
+
+impl<Item, Collection> TryFromIterator<Item> for Unfallible<Collection>
+where
+    Collection: FromIterator<Item>,
+{
+    type Error = UnreachableError;
+
+    #[inline(always)]
+    fn try_from_iter<Iterable: IntoIterator<Item = Item>> (
+        iterable: Iterable,
+    ) -> Result<Self, Self::Error>
+    {
+        Ok(Unfallible(Collection::from_iter(iterable)))
+    }
+}
+
+macro_rules! impl_unfallible {
+    ($(
+        impl [$($generics:tt)*] for $Collection:ty, Item = $Item:ty
+        $(, where [$($bounds:tt)*])?
+        ;
+    )*) => ($(
+        impl<$($generics)*> TryFromIterator<$Item> for $Collection
+        $(where $($bounds)*)?
+        {
+            type Error = UnreachableError;
+
+            #[inline(always)]
+            fn try_from_iter<Iterable: IntoIterator<Item = $Item>> (
+                iterable: Iterable,
+            ) -> Result<Self, Self::Error>
+            {
+                Ok(Self::from_iter(iterable))
+            }
+        }
+    )*)
+}
+
+impl_unfallible! {
+    impl [T] for Vec<T>, Item = T;
+    impl [T] for Box<[T]>, Item = T;
+    impl [T] for ::std::rc::Rc<[T]>, Item = T;
+    impl [T] for ::std::sync::Arc<[T]>, Item = T;
+    impl ['a, T: Clone] for borrow::Cow<'a, [T]>, Item = T;
+    impl [T] for collections::VecDeque<T>, Item = T;
+    impl [T] for collections::LinkedList<T>, Item = T;
+    impl [T: Ord] for collections::BinaryHeap<T>, Item = T;
+    impl [T: Ord] for collections::BTreeSet<T>, Item = T;
+    impl [K: Ord, V] for collections::BTreeMap<K, V>, Item = (K, V);
+    impl [T, S] for collections::HashSet<T, S>, Item = T,
+        where [T: Eq + hash::Hash, S: hash::BuildHasher + Default];
+    impl [K, V, S] for collections::HashMap<K, V, S>, Item = (K, V),
+        where [K: Eq + hash::Hash, S: hash::BuildHasher + Default];
+    impl [] for String, Item = char;
+    impl ['a] for String, Item = &'a char;
+    impl ['a] for String, Item = &'a str;
+    impl [] for String, Item = String;
+    impl [] for String, Item = Box<str>;
+    impl ['a] for String, Item = borrow::Cow<'a, str>;
+    impl [] for (), Item = ();
+}
 
 impl<A: Array> TryFromIterator<A::Item> for A {
     type Error = <StackVec<A> as super::try_into::TryInto<A>>::Error;
@@ -101,8 +336,94 @@ impl<A: Array> TryFromIterator<A::Item> for A {
         iterable: Iterable,
     ) -> Result<Self, Self::Error>
     {
-        let stackvec = StackVec::from_iter(iterable);
-        stackvec.try_into()
+        // The elements are collected within the error returned when there
+        // are too few of them, rather than moving the (possibly big)
+        // `StackVec` into it afterwards.
+        let mut result = Err(IncompleteArrayError { stackvec: StackVec::new() });
+        if let Err(ref mut error) = result {
+            error.stackvec.extend(iterable);
+            if !error.stackvec.is_full() {
+                return result;
+            };
+        };
+        match result {
+            Ok(array) => Ok(array),
+            Err(error) => error.stackvec.try_into(),
+        }
+    }
+}
+
+macro_rules! impl_tuples {(
+    $( [$N:expr] $($var:ident)+ ; )*
+) => ($(
+    /// Same as the array of the same length, which is then destructured.
+    impl<T> TryFromIterator<T> for ($(tuple_item!($var, T),)+) {
+        type Error = IncompleteArrayError<[T; $N]>;
+
+        #[inline]
+        fn try_from_iter<Iterable: IntoIterator<Item = T>> (
+            iterable: Iterable,
+        ) -> Result<Self, Self::Error>
+        {
+            let [$($var),+] = <[T; $N]>::try_from_iter(iterable)?;
+            Ok(($($var,)+))
+        }
+    }
+)*)}
+
+macro_rules! tuple_item {(
+    $var:ident, $T:ty
+) => (
+    $T
+)}
+
+impl_tuples! {
+    [1] _0;
+    [2] _0 _1;
+    [3] _0 _1 _2;
+    [4] _0 _1 _2 _3;
+    [5] _0 _1 _2 _3 _4;
+    [6] _0 _1 _2 _3 _4 _5;
+    [7] _0 _1 _2 _3 _4 _5 _6;
+    [8] _0 _1 _2 _3 _4 _5 _6 _7;
+    [9] _0 _1 _2 _3 _4 _5 _6 _7 _8;
+    [10] _0 _1 _2 _3 _4 _5 _6 _7 _8 _9;
+    [11] _0 _1 _2 _3 _4 _5 _6 _7 _8 _9 _10;
+    [12] _0 _1 _2 _3 _4 _5 _6 _7 _8 _9 _10 _11;
+}
+
+/// Unlike [`StackVec`]'s [`FromIterator`] implementation, which silently
+/// ignores the elements that do not fit, this fails with a
+/// [`CollectOverflowError`] when there are more elements than the
+/// [`StackVec::CAPACITY`].
+impl<A: Array, P: OverflowPolicy> TryFromIterator<A::Item> for StackVec<A, P> {
+    type Error = CollectOverflowError<A, P>;
+
+    #[inline]
+    fn try_from_iter<Iterable: IntoIterator<Item = A::Item>> (
+        iterable: Iterable,
+    ) -> Result<Self, Self::Error>
+    {
+        let mut iterator = iterable.into_iter();
+        let mut stackvec = Self::new_with_policy();
+        stackvec.extend(iterator.by_ref().take(A::LEN));
+        match iterator.next() {
+            None => Ok(stackvec),
+            Some(rejected) => Err(CollectOverflowError::new(stackvec, rejected)),
+        }
+    }
+}
+
+/// Same as [`StackVec`]'s.
+impl<A: Array + Copy> TryFromIterator<A::Item> for CopyStackVec<A> {
+    type Error = CollectOverflowError<A>;
+
+    #[inline]
+    fn try_from_iter<Iterable: IntoIterator<Item = A::Item>> (
+        iterable: Iterable,
+    ) -> Result<Self, Self::Error>
+    {
+        StackVec::try_from_iter(iterable).map(Self::from)
     }
 }
 
@@ -110,6 +431,26 @@ impl<A: Array> TryFromIterator<A::Item> for A {
 mod tests {
     use crate::prelude::*;
 
+    #[test]
+    fn tuples ()
+    {
+        let (host, port, proto) =
+            "localhost:8080:tcp".split(':')
+            .try_collect()
+            .unwrap();
+        assert_eq!((host, port, proto), ("localhost", "8080", "tcp"));
+
+        let (only,): (String,) =
+            Some(String::from("x")).into_iter().try_collect().unwrap();
+        assert_eq!(only, "x");
+
+        let error =
+            (0 .. 11)
+            .try_collect::<(_, _, _, _, _, _, _, _, _, _, _, _)>()
+            .unwrap_err();
+        assert_eq!(error.missing(), 1);
+    }
+
     #[test]
     fn it_works_with_enough_elements ()
     {
@@ -132,4 +473,149 @@ mod tests {
             .try_collect()
             .expect("Missing elements to collect");
     }
+
+    #[test]
+    fn exact_detects_surplus ()
+    {
+        use crate::error::ExactCollectError;
+
+        let mut iterator = 0 .. 20;
+        match iterator.by_ref().try_collect_exact::<[_; 15]>() {
+            Err(ExactCollectError::TooMany { array, surplus }) => {
+                assert_eq!(array[14], 14);
+                assert_eq!(surplus, 15);
+            },
+            _ => panic!("Expected surplus elements"),
+        };
+        assert_eq!(iterator.next(), Some(16));
+
+        assert!(
+            (0 .. 15).try_collect_exact::<[_; 15]>().is_ok()
+        );
+        match (0 .. 10).try_collect_exact::<[_; 15]>() {
+            Err(ExactCollectError::TooFew(error)) => {
+                assert_eq!(error.missing(), 5);
+            },
+            _ => panic!("Expected missing elements"),
+        };
+    }
+
+    #[test]
+    fn stackvec_reports_overflow ()
+    {
+        let stackvec: StackVec<[_; 15]> =
+            (0 .. 15)
+            .try_collect()
+            .expect("Too many elements to collect");
+        assert!(stackvec.is_full());
+        let stackvec: CopyStackVec<[_; 15]> =
+            (0 .. 10)
+            .try_collect()
+            .expect("Too many elements to collect");
+        assert_eq!(stackvec.len(), 10);
+
+        let (stackvec, rejected) =
+            (0 .. 20)
+            .map(|x| x.to_string())
+            .try_collect::<StackVec<[_; 15]>>()
+            .unwrap_err()
+            .into_inner();
+        assert_eq!(stackvec[14], "14");
+        assert_eq!(rejected, "15");
+    }
+
+    #[test]
+    fn unfallible_collections ()
+    {
+        use ::std::collections::HashMap;
+        use crate::Unfallible;
+
+        fn try_collect_squares<C: TryFromIterator<(u8, u32)>> ()
+            -> Result<C, C::Error>
+        {
+            (0 .. 4_u8).map(|x| (x, u32::from(x) * u32::from(x))).try_collect()
+        }
+
+        let array: [_; 4] = try_collect_squares().unwrap();
+        let map =
+            try_collect_squares::<HashMap<_, _>>()
+                .unwrap_or_else(|e| e.unreachable());
+        let Unfallible(stackvec) =
+            try_collect_squares::<Unfallible<StackVec<[_; 2]>>>()
+                .unwrap_or_else(|e| e.unreachable());
+        assert_eq!(array.len(), map.len());
+        assert_eq!(map[&3], 9);
+        assert_eq!(stackvec.as_slice(), &[(0, 0), (1, 1)]);
+
+        let s =
+            ["Hello", ", ", "world"]
+            .iter().cloned()
+            .try_collect::<String>()
+            .unwrap_or_else(|e| e.unreachable());
+        assert_eq!(s, "Hello, world");
+    }
+
+    #[test]
+    fn short_circuits_on_errors ()
+    {
+        use crate::error::{NoneError, ShortCircuitError};
+
+        struct NoClone(u8);
+
+        let array: [_; 3] =
+            (0 .. 5)
+            .map(|x| Some(NoClone(x)))
+            .try_collect_ok()
+            .expect("Missing elements to collect");
+        assert_eq!(array[2].0, 2);
+
+        let mut iterator =
+            (0 .. 5)
+            .map(|x| if x == 2 { Err(x) } else { Ok(NoClone(x)) });
+        match iterator.by_ref().try_collect_ok::<[_; 4]>() {
+            Err(ShortCircuitError::Item(2)) => {},
+            _ => panic!("Expected to stop at the error"),
+        };
+        assert_eq!(iterator.next().unwrap().ok().unwrap().0, 3);
+
+        match
+            vec![Some(0), None]
+            .into_iter()
+            .try_collect_ok::<[_; 2]>()
+        {
+            Err(ShortCircuitError::Item(NoneError)) => {},
+            _ => panic!("Expected to stop at `None`"),
+        };
+
+        match
+            (0 .. 2)
+            .map(Ok::<_, ()>)
+            .try_collect_ok::<[_; 4]>()
+        {
+            Err(ShortCircuitError::Incomplete(error)) => {
+                assert_eq!(error.missing(), 2);
+            },
+            _ => panic!("Expected missing elements"),
+        };
+    }
+
+    #[test]
+    fn it_gives_back_the_incomplete_stackvec ()
+    {
+        struct NoClone(u8);
+
+        let error =
+            (0 .. 10)
+            .map(NoClone)
+            .try_collect::<[_; 15]>()
+            .err()
+            .unwrap();
+        assert_eq!(error.len(), 10);
+        assert_eq!(error.missing(), 5);
+        assert_eq!(format!("{}", error), "collected 10 of 15 elements");
+        let mut stackvec = error.into_inner();
+        stackvec.extend((10 .. 20).map(NoClone));
+        let array: [_; 15] = stackvec.try_into().ok().unwrap();
+        assert_eq!(array[14].0, 14);
+    }
 }
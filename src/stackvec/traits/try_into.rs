@@ -57,7 +57,7 @@ pub trait TryInto<Dst> {
 /// 
 /// let mut vec: StackVec<Array>
 ///     = ["Hello", "world!"] // First 2 values
-///         .into_iter()
+///         .iter().cloned()
 ///         .map(String::from)
 ///         .collect();
 ///
@@ -77,24 +77,37 @@ pub trait TryInto<Dst> {
 /// let array: Array = vec.try_into().unwrap(); // conversion can now be successful
 /// ```
 ///
+/// When the array is built from scratch, [`ArrayExt`]'s constructors
+/// (_e.g._, [`from_fn`][`ArrayExt::from_fn`]) are simpler:
+///
+/// ```
+/// # use ::stackvec::prelude::*;
+/// let array = <[String; 64]>::from_fn(|i| match i {
+///     0 => String::from("Hello"),
+///     1 => String::from("world!"),
+///     _ => String::new(),
+/// });
+/// assert_eq!(array[1], "world!");
+/// ```
+///
 /// [`array`]: https://doc.rust-lang.org/std/primitive.array.html
-impl<A: Array> TryInto<A> for StackVec<A>
+impl<A: Array, P: OverflowPolicy> TryInto<A> for StackVec<A, P>
 {
-	type Error = IncompleteArrayError;
+	type Error = IncompleteArrayError<A, P>;
 
 	#[inline(always)]
 	fn try_into (
-		self: StackVec<A>,
+		self: Self,
 	) -> Result<A, Self::Error>
 	{
 		if self.len == Self::CAPACITY {
-			let array_ptr: *const mem::ManuallyDrop<A> = &self.array;
+			let array_ptr: *const mem::MaybeUninit<A> = &self.array;
 			mem::forget(self);
-			Ok(mem::ManuallyDrop::into_inner(
-				unsafe { ptr::read(array_ptr) }
-			))
+			Ok(unsafe {
+				ptr::read(array_ptr).assume_init()
+			})
 		} else {
-			Err(IncompleteArrayError)
+			Err(IncompleteArrayError::new(self))
 		}
 	}
 }
@@ -37,7 +37,7 @@ fn build_array_but_incomplete ()
 fn array_into_iter ()
 {
 	assert_eq!(
-		[3, 4, 6, 8].into_iter().collect::<Vec<_>>(),
+		IntoIter::from([3, 4, 6, 8]).collect::<Vec<_>>(),
 		vec![3, 4, 6, 8],
 	);
 }
@@ -45,15 +45,14 @@ fn array_into_iter ()
 #[test]
 fn array_into_iter_dropped ()
 {
-	let _ = [3, 4, 6, 8].into_iter();
+	let _ = IntoIter::from([3, 4, 6, 8]);
 }
 
 #[test]
 fn array_map ()
 {
 	let array: [_; 4] =
-		[3, 4, 6, 8]
-		.into_iter()
+		IntoIter::from([3, 4, 6, 8])
 		.map(|x| 2 * x)
 		.try_collect()
 		.expect("Missing elements to collect");
@@ -73,3 +72,20 @@ fn try_push_to_full_stackvec ()
 	stackvec.try_push(0).unwrap();
 	stackvec.try_push(0).unwrap();
 }
+
+#[test]
+fn const_stackvec ()
+{
+	static EMPTY: StackVec<[String; 8]> = StackVec::new();
+	static FULL: StackVec<[u8; 4]> = StackVec::from_array([3, 4, 6, 8]);
+	static FULL_LEN: usize = FULL.as_slice().len();
+	static IS_EMPTY: bool = EMPTY.is_empty();
+	const EMPTY_COPY: CopyStackVec<[u8; 8]> = CopyStackVec::new();
+
+	assert!(IS_EMPTY);
+	assert_eq!(EMPTY.capacity(), 8);
+	assert_eq!(FULL_LEN, 4);
+	assert!(FULL.is_full());
+	assert_eq!(FULL.as_slice(), &[3, 4, 6, 8]);
+	assert!(EMPTY_COPY.is_empty());
+}
@@ -2,6 +2,7 @@
 
 extern crate stackvec;
 use stackvec::prelude::*;
+use stackvec::IntoIter;
 
 use ::std::iter::{
 	self,
@@ -16,7 +17,7 @@ mod counted_instances {
 	use ::std::cell::Cell;
 
 	thread_local! {
-		static INSTANCES_COUNT: Cell<isize> = Cell::new(0);
+		static INSTANCES_COUNT: Cell<isize> = const { Cell::new(0) };
 	}
 
 	#[derive(Debug)]
@@ -167,7 +168,7 @@ fn into_iter ()
 fn array_into_iter ()
 {
 	assert_eq!(
-		Vec::from_iter(NUMBERS.into_iter()),
+		Vec::from_iter(IntoIter::from(NUMBERS)),
 		Vec::from_iter(NUMBERS.iter().cloned()),
 	);
 }
@@ -218,3 +219,156 @@ fn into_iter_with_drop_partial_right ()
 	Instance::count_assert_balanced();
 }
 
+
+#[test]
+fn fill_spare_capacity ()
+{
+	use counted_instances::*;
+	{
+		let mut array = StackVec::<[Instance; 8]>::default();
+		array.push_or_ignore(Instance::new());
+		let filled = {
+			let (init, spare) = array.split_at_spare_mut();
+			assert_eq!(init.len(), 1);
+			assert_eq!(spare.len(), 7);
+			for slot in &mut spare[.. 4] {
+				*slot = ::std::mem::MaybeUninit::new(init[0].clone());
+			};
+			4
+		};
+		unsafe {
+			array.set_len(1 + filled);
+		};
+		assert_eq!(array.len(), 5);
+		assert_eq!(array.spare_capacity_mut().len(), 3);
+	}
+	Instance::count_assert_balanced();
+}
+
+#[test]
+fn change_capacity_with_drop ()
+{
+	use counted_instances::*;
+	{
+		let small = StackVec::<[Instance; 4]>::from_iter(
+			iter::repeat_with(Instance::new).take(3)
+		);
+		let big: StackVec<[Instance; 16]> = small.widen();
+		assert_eq!(big.len(), 3);
+		let big = big.try_narrow::<[Instance; 2]>().unwrap_err();
+		let mut exact = big.try_narrow::<[Instance; 3]>().unwrap();
+		assert!(exact.is_full());
+		exact.extend(StackVec::<[Instance; 2]>::from_iter(
+			iter::repeat_with(Instance::new)
+		));
+		assert!(exact.is_full());
+	}
+	Instance::count_assert_balanced();
+}
+
+#[test]
+fn compare_across_capacities ()
+{
+	let small = StackVec::<[u8; 4]>::from_iter(0 .. 3);
+	let big = StackVec::<[u8; 64]>::from_iter(0 .. 3);
+	assert_eq!(small, big);
+	assert!(small < StackVec::<[u8; 8]>::from_iter(1 .. 2));
+	assert!(big > StackVec::<[u8; 2]>::from_iter(0 .. 2));
+
+	let mut sorted = [
+		StackVec::<[u8; 4]>::from_iter(1 .. 3),
+		StackVec::from_iter(0 .. 4),
+		StackVec::from_iter(0 .. 2),
+	];
+	sorted.sort();
+	assert_eq!(sorted[0], StackVec::<[u8; 2]>::from_iter(0 .. 2));
+	assert_eq!(sorted[2], StackVec::<[u8; 2]>::from_iter(1 .. 3));
+}
+
+#[test]
+fn array_chunks_round_trip_with_drop ()
+{
+	use counted_instances::*;
+	{
+		let instances = StackVec::<[Instance; 8]>::from_iter(
+			iter::repeat_with(Instance::new).take(7)
+		);
+		let mut chunks = instances.into_array_chunks::<[Instance; 3]>();
+		let first = chunks.next().unwrap();
+		let last = chunks.next_back().unwrap();
+		assert_eq!(chunks.len(), 0);
+		assert_eq!(chunks.into_remainder().len(), 1);
+
+		let mut pairs = StackVec::<[[Instance; 3]; 2]>::new();
+		pairs.extend(vec![first, last]);
+		let flat: StackVec<[Instance; 6]> = pairs.flatten();
+		assert!(flat.is_full());
+	}
+	Instance::count_assert_balanced();
+}
+
+#[test]
+fn saturate_with_drop ()
+{
+	use counted_instances::*;
+	use stackvec::policy::Saturate;
+	{
+		let mut array = StackVec::<[Instance; 3], Saturate>::new_with_policy();
+		array.extend(iter::repeat_with(Instance::new).take(5));
+		array.push(Instance::new());
+		array.insert(1, Instance::new());
+		array.insert(0, Instance::new());
+		assert_eq!(Instance::total_count(), 3);
+	}
+	Instance::count_assert_balanced();
+}
+
+#[test]
+fn try_extend_with_drop ()
+{
+	use counted_instances::*;
+	{
+		let mut array = StackVec::<[Instance; 3]>::new();
+		let mut instances = iter::repeat_with(Instance::new).take(5);
+		// `Take<RepeatWith>` knows its length: nothing is consumed
+		let overflow = array.try_extend(instances.by_ref()).unwrap_err();
+		assert!(overflow.rejected().is_none());
+		assert_eq!(Instance::total_count(), 0);
+		// Without the length hint, the fourth element is rejected
+		let overflow = array
+			.try_extend(instances.by_ref().filter(|_| true))
+			.unwrap_err();
+		assert!(array.is_full());
+		assert!(overflow.rejected().is_some());
+		assert_eq!(Instance::total_count(), 4);
+		drop(overflow); // drops the rejected element, not the remaining ones
+		assert_eq!(Instance::total_count(), 3);
+		assert_eq!(instances.len(), 1);
+		array.pop();
+		assert!(array.try_extend(instances).is_ok());
+		assert!(array.is_full());
+	}
+	Instance::count_assert_balanced();
+}
+
+#[test]
+fn prelude_does_not_shadow_slice_methods ()
+{
+	assert_eq!(["a", "b"].concat(), "ab");
+	assert_eq!([[1, 2], [3, 4]].concat(), [1, 2, 3, 4]);
+	assert_eq!(["a", "b"].join("-"), "a-b");
+	let parts: Vec<&[u8]> = [1_u8, 0, 2].split(|&b| b == 0).collect();
+	assert_eq!(parts, [&[1][..], &[2][..]]);
+}
+
+#[test]
+#[allow(array_into_iter, clippy::into_iter_on_ref)]
+fn prelude_does_not_shadow_std_array_into_iter ()
+{
+	// Edition 2015: `std`'s autoref-ing, by-reference `.into_iter()`
+	let array = [String::from("a")];
+	let first: Option<&String> = array.into_iter().next();
+	assert_eq!(first.unwrap(), "a");
+	let first: Option<String> = IntoIter::from(array).next();
+	assert_eq!(first.unwrap(), "a");
+}
@@ -0,0 +1,80 @@
+extern crate stackvec;
+use stackvec::prelude::*;
+
+use ::std::mem::{
+	self,
+	MaybeUninit,
+};
+
+type Numbers = StackVec<[u32; 8]>;
+
+/// The layout C code sees, as documented in `StackVec`.
+#[repr(C)]
+struct CNumbers {
+	array: [u32; 8],
+	len: usize,
+}
+
+/// Doubles the elements and appends their (original) sum, C-side.
+extern "C" fn double_and_push_sum (numbers: *mut CNumbers)
+{
+	let numbers = unsafe { &mut *numbers };
+	let len = numbers.len;
+	let mut sum = 0;
+	for x in &mut numbers.array[.. len] {
+		sum += *x;
+		*x *= 2;
+	};
+	numbers.array[len] = sum;
+	numbers.len = len + 1;
+}
+
+#[test]
+fn layout ()
+{
+	assert_eq!(mem::size_of::<Numbers>(), mem::size_of::<CNumbers>());
+	assert_eq!(mem::align_of::<Numbers>(), mem::align_of::<CNumbers>());
+	assert_eq!(
+		mem::size_of::<CopyStackVec<[u32; 8]>>(),
+		mem::size_of::<CNumbers>(),
+	);
+}
+
+#[test]
+fn round_trip_through_extern_c_fn ()
+{
+	let callback: extern "C" fn(*mut CNumbers) = double_and_push_sum;
+	let mut numbers: Numbers = (1 ..= 3).collect();
+	callback(&mut numbers as *mut Numbers as *mut CNumbers);
+	assert_eq!(numbers.as_slice(), &[2, 4, 6, 6]);
+}
+
+#[test]
+fn raw_parts ()
+{
+	let numbers: StackVec<[String; 4]> =
+		["Hello", "world"]
+		.iter()
+		.map(|&s| String::from(s))
+		.collect();
+	let (mut array, len) = numbers.into_raw_parts();
+	assert_eq!(len, 2);
+	unsafe {
+		(array.as_mut_ptr() as *mut String).add(len).write(String::from("!"));
+	};
+	let mut numbers = unsafe { StackVec::<_>::from_raw_parts(array, len + 1) };
+	assert_eq!(numbers.as_slice(), &["Hello", "world", "!"]);
+
+	let last = unsafe {
+		let last = ::std::ptr::read(numbers.as_ptr().add(2));
+		numbers.set_len(2);
+		last
+	};
+	assert_eq!(last, "!");
+	assert_eq!(numbers.as_slice(), &["Hello", "world"]);
+
+	let empty = unsafe {
+		StackVec::<[String; 4]>::from_raw_parts(MaybeUninit::uninit(), 0)
+	};
+	assert!(empty.is_empty());
+}